@@ -1,7 +1,9 @@
 mod utils;
 mod tracer;
 mod scene_parser;
-use std::env;
+mod obj_loader;
+use std::{env, fs};
+use std::time::Duration;
 
 fn main() {
     let mut args = env::args();
@@ -11,6 +13,18 @@ fn main() {
 
     let mut samples = utils::SAMPLES;
     let mut max_bounce = utils::BOUNCE_AMOUNT;
+    let mut threads = utils::default_threads();
+    let mut width: Option<usize> = None;
+    let mut height: Option<usize> = None;
+    let mut tone_map: Option<utils::ToneMap> = None;
+    let mut exposure: Option<f32> = None;
+    let mut integrator_name = "path".to_string();
+    let mut pass_samples: Option<usize> = None;
+    let mut snapshot_interval: Option<f32> = None;
+    let mut checkpoint = false;
+    let mut adaptive: Option<f32> = None;
+    let mut heatmap = false;
+    let mut seed: Option<u64> = None;
 
     let scene_file_path = args.nth(1).unwrap().to_string();
     let mut output_path = "out".to_string();
@@ -20,21 +34,112 @@ fn main() {
             Some("-f") => {fast = true;},
             Some("-s") => {samples = arg.get(2..).unwrap().parse::<usize>().unwrap();}
             Some("-b") => {max_bounce = arg.get(2..).unwrap().parse::<usize>().unwrap();}
+            Some("-w") => {width = Some(arg.get(2..).unwrap().parse::<usize>().unwrap());}
+            Some("-h") => {height = Some(arg.get(2..).unwrap().parse::<usize>().unwrap());}
+            Some("-m") => {
+                tone_map = utils::ToneMap::from_name(arg.get(2..).unwrap());
+                if tone_map.is_none() {println!("Unknown tone mapping operator {}", arg)}
+            }
+            Some("-i") => {
+                let name = arg.get(2..).unwrap();
+                if tracer::integrator_from_name(name, 0).is_some() {
+                    integrator_name = name.to_string();
+                } else {
+                    println!("Unknown integrator {}", arg)
+                }
+            }
+            Some("-p") => {pass_samples = Some(arg.get(2..).unwrap().parse::<usize>().unwrap());}
+            Some("-k") => {snapshot_interval = Some(arg.get(2..).unwrap().parse::<f32>().unwrap());}
+            Some("-c") => {checkpoint = true;}
+            Some("-a") => {adaptive = Some(arg.get(2..).unwrap().parse::<f32>().unwrap());}
+            Some("-H") => {heatmap = true;}
+            Some("-e") => {exposure = Some(arg.get(2..).unwrap().parse::<f32>().unwrap());}
+            Some("-t") => {threads = arg.get(2..).unwrap().parse::<usize>().unwrap();}
+            Some("--") if arg.starts_with("--seed=") => {seed = Some(arg.get(7..).unwrap().parse::<u64>().unwrap());}
             Some("-@") => {output_path = arg.get(2..).unwrap().to_string()}
             _ => {println!("Unknown argument {}", arg)}
         }
     }
 
-    let mut parser = scene_parser::Parser::new(&scene_file_path.to_string());
-    parser.parse();
+    if snapshot_interval.is_some_and(|_interval| Duration::try_from_secs_f32(_interval).is_err()) {
+        eprintln!("The time between snapshots must be 0 or more seconds");
+        std::process::exit(1);
+    }
+
+    if width == Some(0) || height == Some(0) {
+        eprintln!("The resolution must be at least 1 pixel wide and high");
+        std::process::exit(1);
+    }
+
+    // Without a seed every render is different, the seed is printed so that a render can be repeated
+    let seed = seed.unwrap_or_else(utils::random_seed);
+    let mut parser = scene_parser::Parser::new(&scene_file_path.to_string(), seed);
+    if let Err(errors) = parser.parse() {
+        for error in &errors {
+            eprintln!("{}\n", error);
+        }
+        eprintln!("{} error{} found in {}, nothing was rendered.", errors.len(), if errors.len() == 1 {""} else {"s"}, scene_file_path);
+        std::process::exit(1);
+    }
+
+    // Resolution given as arguments overrides the one in the scene. If only one side is given the aspect ratio is kept
+    let (scene_width, scene_height) = parser.camera.resolution();
+    let aspect_ratio = scene_width as f32 / scene_height as f32;
+    match (width, height) {
+        (Some(w), Some(h)) => parser.camera.set_resolution(w, h),
+        (Some(w), None) => parser.camera.set_resolution(w, ((w as f32 / aspect_ratio) as usize).max(1)),
+        (None, Some(h)) => parser.camera.set_resolution(((h as f32 * aspect_ratio) as usize).max(1), h),
+        (None, None) => {}
+    }
+
+    // Tone mapping and exposure given as arguments override the FILM command of the scene
+    let mut film = parser.camera.film();
+    film.tone_map = tone_map.unwrap_or(film.tone_map);
+    film.exposure = exposure.unwrap_or(film.exposure);
+    parser.camera.set_film(film);
 
     if fast {
-        println!("Fast render of {} started.", scene_file_path);
-        parser.camera.fast_render(&mut parser.world, output_path.as_str())
+        println!("Fast render of {} started. Seed {}.", scene_file_path, seed);
+        parser.camera.fast_render(&parser.world, output_path.as_str(), threads, seed)
             .expect("Error While Rendering");
     } else {
-        println!("Render of {} started. {} Samples, {} Light Bounces.", scene_file_path, samples, max_bounce);
-        parser.camera.render(&mut parser.world, output_path.as_str(), samples, max_bounce)
+        let (w, h) = parser.camera.resolution();
+        let integrator = tracer::integrator_from_name(&integrator_name, max_bounce).unwrap();
+        println!("Render of {} started. {}x{} Pixels, {} Samples, {} Light Bounces, {} Threads, {} Integrator, Seed {}.", scene_file_path, w, h, samples, max_bounce, threads, integrator_name, seed);
+        // Progressive rendering if either the samples per pass or the time between snapshots is given
+        let progressive = match (pass_samples, snapshot_interval) {
+            (None, None) => None,
+            (_, _) => Some(tracer::Progressive {
+                pass_samples: pass_samples.unwrap_or(utils::PASS_SAMPLES),
+                interval: Duration::from_secs_f32(snapshot_interval.unwrap_or(0.0))
+            })
+        };
+
+        // The checkpoint only belongs to the same scene file (and the models and images it reads) rendered with the same integrator, light bounces and seed
+        let checkpoint = if checkpoint {
+            let mut scene_data = fs::read(&scene_file_path).unwrap_or_default();
+            for file in parser.files.iter() {
+                scene_data.extend(fs::read(file).unwrap_or_default());
+            }
+            scene_data.extend(format!("{} {} {}", integrator_name, max_bounce, seed).bytes());
+            Some(tracer::Checkpoint {
+                path: format!("images/{}.ckpt", output_path),
+                scene_hash: utils::fnv1a(&scene_data)
+            })
+        } else {
+            None
+        };
+
+        let settings = tracer::RenderSettings {
+            samples,
+            threads,
+            progressive,
+            checkpoint,
+            adaptive,
+            heatmap,
+            seed
+        };
+        parser.camera.render(&parser.world, integrator.as_ref(), output_path.as_str(), &settings)
             .expect("Error While Rendering");
     }
 }
@@ -0,0 +1,161 @@
+use crate::utils::{Vector3, Matrix4};
+use std::{collections::HashMap, fmt, fs, path::Path};
+
+/// # `ObjError`
+/// Error found while loading a Wavefront .obj file. Holds the file, the line (0 if the error is not tied to a line) and what went wrong
+#[derive(Debug)]
+pub struct ObjError {
+    pub file: String,
+    pub line: usize,
+    pub message: String
+}
+
+impl fmt::Display for ObjError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.line {
+            0 => write!(f, "{}: {}", self.file, self.message),
+            _ => write!(f, "{}:{}: {}", self.file, self.line, self.message)
+        }
+    }
+}
+
+/// # `ObjModel`
+/// The triangles of a loaded .obj file as vertex and index buffers ready to be made into a `Mesh`.
+/// `normals` is either empty or holds one normal per vertex
+pub struct ObjModel {
+    pub vertices: Vec<Vector3>,
+    pub normals: Vec<Vector3>,
+    pub indices: Vec<[usize; 3]>
+}
+
+impl ObjModel {
+    /// # `load`
+    /// Reads and parses the .obj file at the given path. Polygons are triangulated as fans.
+    /// Only vertices (`v`), normals (`vn`) and faces (`f`) are used, other statements are ignored.
+    /// If any face corner has no normal the normals of the whole model are dropped and it is shaded flat
+    pub fn load(path: &Path) -> Result<ObjModel, ObjError> {
+        let file = path.display().to_string();
+        let error = |line: usize, message: String| ObjError {file: file.clone(), line, message};
+
+        let text = fs::read_to_string(path).map_err(|_error| error(0, format!("could not read file ({})", _error)))?;
+
+        let mut positions: Vec<Vector3> = Vec::new();
+        let mut obj_normals: Vec<Vector3> = Vec::new();
+        let mut faces: Vec<Vec<(usize, Option<usize>)>> = Vec::new(); // (position, normal) of every corner
+
+        for (line_idx, _line) in text.lines().enumerate() {
+            let line_num = line_idx + 1;
+            let mut tokens = _line.split_whitespace();
+
+            match tokens.next() {
+                Some("v") => positions.push(Self::parse_vector(tokens, line_num, &error)?),
+                Some("vn") => {
+                    let normal = Self::parse_vector(tokens, line_num, &error)?;
+                    if normal.norm_squared() == 0.0 {
+                        return Err(error(line_num, "a vertex normal can not have length 0".to_string()));
+                    }
+                    obj_normals.push(normal.unit());
+                }
+                Some("f") => {
+                    let corners = tokens
+                        .map(|_corner| Self::parse_corner(_corner, positions.len(), obj_normals.len())
+                            .map_err(|_message| error(line_num, _message)))
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    if corners.len() < 3 {
+                        return Err(error(line_num, format!("a face needs at least 3 vertices, {} given", corners.len())));
+                    }
+                    faces.push(corners);
+                }
+                _ => {} // Comments, groups, materials, texture coordinates etc.
+            }
+        }
+
+        if faces.is_empty() {
+            return Err(error(0, "the file has no faces".to_string()));
+        }
+
+        // Corners can use different normals for the same position, so every unique (position, normal) pair becomes a vertex
+        let smooth = faces.iter().all(|_face| _face.iter().all(|_corner| _corner.1.is_some()));
+        let mut model = ObjModel {
+            vertices: Vec::new(),
+            normals: Vec::new(),
+            indices: Vec::new()
+        };
+        let mut unique: HashMap<(usize, Option<usize>), usize> = HashMap::new();
+
+        for corners in faces.iter() {
+            let indices = corners
+                .iter()
+                .map(|&(_pos, _normal)| {
+                    let key = (_pos, if smooth {_normal} else {None});
+                    *unique.entry(key).or_insert_with(|| {
+                        model.vertices.push(positions[_pos]);
+                        if smooth {
+                            model.normals.push(obj_normals[_normal.unwrap()]);
+                        }
+                        model.vertices.len() - 1
+                    })
+                })
+                .collect::<Vec<usize>>();
+
+            for i in 1..indices.len() - 1 {
+                model.indices.push([indices[0], indices[i], indices[i + 1]]);
+            }
+        }
+
+        Ok(model)
+    }
+
+    /// # `transform`
+    /// Scales the model by the given `f32`, then rotates it by the given angles in degrees around the x, y and z axis (in that order) and then moves it by the given translation `Vector3`.
+    /// The scale has to be positive, a mirrored model would keep its face winding and shade inside-out
+    pub fn transform(&mut self, scale: f32, rotation: Vector3, translation: Vector3) {
+        let rotation = Matrix4::rotation(rotation);
+        let matrix = Matrix4::translation(translation) * rotation * Matrix4::scaling(Vector3::new(scale, scale, scale));
+
+        for vertex in self.vertices.iter_mut() {
+            *vertex = matrix.transform_point(*vertex);
+        }
+
+        for normal in self.normals.iter_mut() {
+            *normal = rotation.transform_vector(*normal);
+        }
+    }
+
+    fn parse_vector<'a>(tokens: impl Iterator<Item = &'a str>, line: usize, error: &impl Fn(usize, String) -> ObjError) -> Result<Vector3, ObjError> {
+        let vals = tokens
+            .take(3)
+            .map(|_val| _val.parse::<f32>().map_err(|_| error(line, format!("\"{}\" is not a number", _val))))
+            .collect::<Result<Vec<f32>, _>>()?;
+
+        match Vector3::from_vec(vals) {
+            Some(v) => Ok(v),
+            None => Err(error(line, "expected 3 coordinates".to_string()))
+        }
+    }
+
+    // Parses a face corner `v`, `v/vt`, `v//vn` or `v/vt/vn` into 0-based position and normal indices
+    fn parse_corner(corner: &str, position_count: usize, normal_count: usize) -> Result<(usize, Option<usize>), String> {
+        let mut parts = corner.split('/');
+        let position = Self::resolve_index(parts.next().unwrap_or(""), position_count, "vertex")?;
+        let normal = match parts.nth(1) {
+            Some(_idx) if !_idx.is_empty() => Some(Self::resolve_index(_idx, normal_count, "normal")?),
+            _ => None
+        };
+
+        Ok((position, normal))
+    }
+
+    // .obj indices start at 1, negative indices count back from the latest element
+    fn resolve_index(index: &str, count: usize, kind: &str) -> Result<usize, String> {
+        let idx = index.parse::<i64>().map_err(|_| format!("\"{}\" is not a valid {} index", index, kind))?;
+        let resolved = if idx < 0 {count as i64 + idx} else {idx - 1};
+
+        if idx == 0 || resolved < 0 || resolved >= count as i64 {
+            return Err(format!("{} index {} out of range, {} {}s defined so far", kind, idx, count, kind));
+        }
+
+        Ok(resolved as usize)
+    }
+}
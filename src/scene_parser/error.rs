@@ -0,0 +1,69 @@
+use crate::obj_loader::ObjError;
+use std::fmt;
+
+/// # `SceneErrorKind`
+/// Enum type to specify what went wrong in a .scene file
+#[derive(Debug)]
+pub enum SceneErrorKind {
+    Io(String),
+    UnknownCommand,
+    UnknownType(&'static str),
+    ArgumentCount {usage: &'static str, given: usize},
+    UndefinedName(&'static str),
+    BadNumber,
+    BadRange,
+    InvalidValue(String),
+    Model(ObjError),
+    Image(String)
+}
+
+/// # `SceneError`
+/// Error found while parsing a .scene file. Holds the file, line (0 if the error is not tied to a line), column and token where it was found,
+/// the text of the line and what went wrong as `SceneErrorKind`
+#[derive(Debug)]
+pub struct SceneError {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub source: String,
+    pub kind: SceneErrorKind
+}
+
+impl SceneError {
+    /// # `description`
+    /// Returns a readable description of what went wrong as `String`
+    pub fn description(&self) -> String {
+        match &self.kind {
+            SceneErrorKind::Io(_error) => format!("could not read file ({})", _error),
+            SceneErrorKind::UnknownCommand => format!("unknown command \"{}\"", self.token),
+            SceneErrorKind::UnknownType(_kind) => format!("unknown {} \"{}\"", _kind, self.token),
+            SceneErrorKind::ArgumentCount {usage, given} => format!("wrong number of values ({} given), expected \"{}\"", given, usage),
+            SceneErrorKind::UndefinedName(_kind) => format!("undefined {} \"{}\"", _kind, self.token),
+            SceneErrorKind::BadNumber => format!("\"{}\" is not a valid number", self.token),
+            SceneErrorKind::BadRange => format!("\"{}\" is not a valid range, expected x_y where x <= y", self.token),
+            SceneErrorKind::InvalidValue(_message) => _message.to_string(),
+            SceneErrorKind::Model(_error) => format!("could not load model, {}", _error),
+            SceneErrorKind::Image(_error) => format!("could not load image \"{}\" ({})", self.token, _error)
+        }
+    }
+}
+
+impl fmt::Display for SceneError {
+    /// Formats the error as `file:line:column: description` followed by the line with the token underlined
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.line == 0 {
+            return write!(f, "{}: error: {}", self.file, self.description());
+        }
+
+        writeln!(f, "{}:{}:{}: error: {}", self.file, self.line, self.column, self.description())?;
+        writeln!(f, "    {}", self.source)?;
+        // Keep tabs so the marker lines up with the token
+        let padding: String = self.source
+            .chars()
+            .take(self.column - 1)
+            .map(|_char| if _char == '\t' {'\t'} else {' '})
+            .collect();
+        write!(f, "    {}{}", padding, "^".repeat(self.token.chars().count().max(1)))
+    }
+}
@@ -0,0 +1,972 @@
+use crate::tracer::{
+    Axis, Camera, Capsule, Checker, Cone, Cuboid, Cylinder, Disk, ImageTexture, Material, MaterialType, Mesh, NoisePattern, NoiseTexture, Object, Plane, Quad, Rectangle, SolidColour, Sphere, Texture,
+    Torus, Transformed, Triangle, World,
+};
+use crate::utils::{Colour, Film, Matrix4, ToneMap, Vector3, random_range, seed_random, IMG_HEIGHT, IMG_WIDTH, ORIGIN, Z_UNIT};
+use crate::obj_loader::ObjModel;
+use std::{collections::HashMap, fs, path::{Path, PathBuf}, str::FromStr, sync::Arc};
+
+mod error;
+
+pub use self::error::{SceneError, SceneErrorKind};
+
+// Random stream of the seed used for the `x_y` values of the file, the pixels of a render use the other streams
+const PARSER_STREAM: u64 = u64::MAX;
+
+/// # `LineError`
+/// Error inside a single line of a .scene file, pointing at the index of the token that caused it
+struct LineError {
+    token: usize,
+    kind: SceneErrorKind
+}
+
+impl LineError {
+    fn new(token: usize, kind: SceneErrorKind) -> LineError {
+        LineError {
+            token,
+            kind
+        }
+    }
+}
+
+/// # `ParseState`
+/// Everything defined so far in the file that later lines can refer to
+struct ParseState {
+    materials: HashMap<String, Material>,
+    textures: HashMap<String, Arc<dyn Texture>>,
+    definitions: HashMap<String, Arc<dyn Object>>,
+    transform: Option<Matrix4> // Pending TRANSLATE, ROTATE and SCALE modifiers
+}
+
+/// # `Parser`
+/// A struct that reads and parses a .scene file and generates the `World` and `Camera` specificed by the file
+pub struct Parser {
+    file_path: String,
+    seed: u64,
+    pub world: World,
+    pub camera: Camera,
+    pub files: Vec<PathBuf> // Model and image files read by the scene
+}
+
+impl Parser {
+    /// `new`
+    /// Initialises the parser by taking the .scene file path and the seed as `u64` of the random `x_y` values
+    pub fn new(file_path: &String, seed: u64) -> Parser {
+        Parser {
+            file_path: file_path.to_string(),
+            seed,
+            world: World::new_empty(),
+            camera: Camera::new(ORIGIN, Z_UNIT, 90.0, IMG_WIDTH, IMG_HEIGHT, 0.0, 1.0),
+            files: Vec::new()
+        }
+    }
+
+    /// `parse`
+    /// Parses the file given to the Parser. This changes the Parser's `World` and `Camera` fields into the data given in the file. The objects of the world are grouped into a bounding volume hierarchy afterwards.
+    /// Lines with errors are skipped and parsing continues so that all errors of the file are returned at once as `Vec<SceneError>`
+    pub fn parse(&mut self) -> Result<(), Vec<SceneError>> {
+
+        seed_random(self.seed, PARSER_STREAM);
+        self.world = World::new_empty();
+        self.files.clear();
+        let mut state = ParseState {
+            materials: HashMap::new(),
+            textures: HashMap::new(),
+            definitions: HashMap::new(),
+            transform: None
+        };
+        let mut errors: Vec<SceneError> = Vec::new();
+
+        // Read file
+        let text = match fs::read_to_string(&self.file_path) {
+            Ok(_text) => _text,
+            Err(_error) => {
+                return Err(vec![SceneError {
+                    file: self.file_path.to_string(),
+                    line: 0,
+                    column: 0,
+                    token: String::new(),
+                    source: String::new(),
+                    kind: SceneErrorKind::Io(_error.to_string())
+                }]);
+            }
+        };
+
+        // (line number, line) of all non-empty lines
+        let lines: Vec<(usize, &str)> = text
+            .lines()
+            .enumerate()
+            .map(|(_idx, _line)| (_idx + 1, _line))
+            .filter(|(_, _line)| !_line.trim().is_empty())
+            .collect();
+
+        let mut big_loop_idx: usize = 0; // Line by line loop
+        let mut in_loop_idx: usize = 0; // Inner loops are ones induced by the ~ command
+
+        while big_loop_idx < lines.len() {
+            let (line_num, source) = lines[big_loop_idx];
+            let tokens = Self::tokenize(source);
+            let data: Vec<String> = tokens.iter().map(|_token| _token.1.to_string()).collect();
+
+            let result = match data[0].as_str() {
+                "~" => Self::parse_repeat(&data).map(|_count| {
+                    in_loop_idx = _count;
+                    big_loop_idx += 1;
+                }),
+                _ => self.parse_line(&data, &mut state)
+            };
+
+            if let Some(_file) = Self::referenced_file(&data).filter(|_| result.is_ok()) {
+                let path = Self::relative_path(&self.file_path, _file);
+                if !self.files.contains(&path) {
+                    self.files.push(path);
+                }
+            }
+
+            if let Err(_error) = result {
+                errors.push(SceneError {
+                    file: self.file_path.to_string(),
+                    line: line_num,
+                    column: tokens[_error.token].0,
+                    token: data[_error.token].to_string(),
+                    source: source.to_string(),
+                    kind: _error.kind
+                });
+                in_loop_idx = 0; // Do not repeat the same error
+            }
+
+            // Transform modifiers only apply to the next object (and all its repetitions)
+            if in_loop_idx == 0 && (data[0] == "OBJ" || data[0] == "DEF") {
+                state.transform = None;
+            }
+
+            // If there is a ~ loop active then reduce the counter and repeat the same line until the counter is zero
+            if in_loop_idx > 0 {
+                in_loop_idx -= 1;
+            } else {
+                big_loop_idx += 1;
+            }
+        }
+
+        self.world.build_bvh();
+
+        if errors.is_empty() {Ok(())} else {Err(errors)}
+    }
+
+    /// # `parse_line`
+    /// Parses a single command given as `Vec<String>` and changes the `World`, `Camera` or what is defined in the `ParseState` based on it
+    fn parse_line(&mut self, data: &[String], state: &mut ParseState) -> Result<(), LineError> {
+        match data[0].as_str() {
+            "CAM" => {
+                let film = self.camera.film();
+                self.camera = Self::parse_cam(data, self.camera.resolution())?;
+                self.camera.set_film(film);
+            }
+            "FILM" => {
+                self.camera.set_film(Self::parse_film(data)?);
+            }
+            "RES" => {
+                Self::expect_args(data, &[3], "RES w h")?;
+                let width = Self::get_int::<usize>(data, 1)?;
+                let height = Self::get_int::<usize>(data, 2)?;
+                if width == 0 || height == 0 {
+                    return Err(LineError::new(if width == 0 {1} else {2}, SceneErrorKind::InvalidValue("the resolution must be at least 1 pixel wide and high".to_string())));
+                }
+
+                self.camera.set_resolution(width, height);
+            }
+            "MAT" => {
+                let mat = Self::parse_mat(data, &state.textures)?;
+
+                state.materials.insert(mat.0, mat.1);
+            }
+            "TEX" => {
+                let tex = Self::parse_tex(data, &state.textures, &self.file_path)?;
+
+                state.textures.insert(tex.0, tex.1);
+            }
+            "OBJ" => {
+                Self::parse_obj(data, &mut self.world, state, &self.file_path)?;
+            }
+            "DEF" => {
+                Self::expect_min_args(data, 3, "DEF name type ...")?;
+
+                // Parse the geometry as if it were an OBJ command but keep it out of the world
+                let obj_data: Vec<String> = ["OBJ".to_string()].into_iter().chain(data[2..].iter().cloned()).collect();
+                let mut geometry = World::new_empty();
+                Self::parse_obj(&obj_data, &mut geometry, state, &self.file_path)
+                    .map_err(|_error| LineError::new(_error.token + 1, _error.kind))?;
+
+                state.definitions.insert(data[1].to_string(), Arc::from(geometry.objects.pop().unwrap()));
+            }
+            "TRANSLATE" | "ROTATE" | "SCALE" => {
+                let transform = Self::parse_transform(data)? * state.transform.unwrap_or_else(Matrix4::identity);
+                if transform.inverse().is_none() {
+                    return Err(LineError::new(0, SceneErrorKind::InvalidValue("the transform flattens the object, it can not be undone".to_string())));
+                }
+
+                state.transform = Some(transform);
+            }
+            "//" => {}
+            _ => {
+                return Err(LineError::new(0, SceneErrorKind::UnknownCommand));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// # `parse_repeat`
+    /// Parses the ~ command given as `Vec<String>` and returns how many times the next line is repeated
+    fn parse_repeat(data: &[String]) -> Result<usize, LineError> {
+        Self::expect_args(data, &[2], "~ x")?;
+        Self::get_int::<usize>(data, 1)
+    }
+
+    /// # `parse_cam`
+    /// Parses the camera data given as `Vec<String>` and returns a Camera with the given settings.
+    /// The aperture and focus distance are optional, without them the camera is a pinhole camera. The focus distance defaults to the distance to the target.
+    /// The camera keeps the given resolution `(usize, usize)`
+    fn parse_cam(data: &[String], resolution: (usize, usize)) -> Result<Camera, LineError> {
+        Self::expect_args(data, &[8, 9, 10], "CAM f1 f2 f3 t1 t2 t3 fov [aperture] [focus]")?;
+
+        let from = Self::get_vector(data, 1)?;
+        let to = Self::get_vector(data, 4)?;
+        let fov = Self::get_val(data, 7)?;
+        if fov <= 0.0 || fov >= 180.0 {
+            return Err(LineError::new(7, SceneErrorKind::InvalidValue("the field of view must be between 0 and 180 degrees".to_string())));
+        }
+        if (to - from).norm_squared() == 0.0 {
+            return Err(LineError::new(4, SceneErrorKind::InvalidValue("the camera target must be different from its origin".to_string())));
+        }
+
+        let aperture = if data.len() > 8 {Self::get_val(data, 8)?} else {0.0};
+        let focus_distance = if data.len() > 9 {Self::get_val(data, 9)?} else {(to - from).norm()};
+        if aperture < 0.0 {
+            return Err(LineError::new(8, SceneErrorKind::InvalidValue("the aperture can not be negative".to_string())));
+        }
+        if focus_distance <= 0.0 {
+            return Err(LineError::new(9, SceneErrorKind::InvalidValue("the focus distance must be greater than 0".to_string())));
+        }
+
+        Ok(Camera::new(from, to, fov, resolution.0, resolution.1, aperture, focus_distance))
+    }
+
+    /// # `parse_film`
+    /// Parses the film data given as `Vec<String>` and returns a `Film` with the given tone mapping operator.
+    /// The exposure in stops and the white point of the extended Reinhard operator are optional
+    fn parse_film(data: &[String]) -> Result<Film, LineError> {
+        Self::expect_args(data, &[2, 3, 4], "FILM toneMap [exposure] [white]")?;
+
+        let mut film = Film {
+            tone_map: match ToneMap::from_name(&data[1]) {
+                Some(_tone_map) => _tone_map,
+                None => return Err(LineError::new(1, SceneErrorKind::UnknownType("tone mapping operator")))
+            },
+            ..Film::default()
+        };
+
+        if data.len() > 2 {
+            film.exposure = Self::get_val(data, 2)?;
+        }
+        if data.len() > 3 {
+            film.white = Self::get_val(data, 3)?;
+            if film.white <= 0.0 {
+                return Err(LineError::new(3, SceneErrorKind::InvalidValue("the white point must be greater than 0".to_string())));
+            }
+        }
+
+        Ok(film)
+    }
+
+    /// # `parse_obj`
+    /// Parses the object data given as `Vec<String>` based on its type. Also takes the `World` struct to add the object into,
+    /// the `ParseState` with the defined materials, geometry and pending transform and the path of the .scene file as `&str`
+    fn parse_obj(
+        data: &[String],
+        world: &mut World,
+        state: &ParseState,
+        scene_path: &str,
+    ) -> Result<(), LineError> {
+        Self::expect_min_args(data, 2, "OBJ type ...")?;
+        let light_count = world.lights.len();
+
+        match data[1].as_str() {
+            "sphere" => {
+                Self::parse_sphere(data, world, &state.materials)?;
+            }
+            "rect" => {
+                Self::parse_rect(data, world, &state.materials)?;
+            }
+            "quad" => {
+                Self::parse_quad(data, world, &state.materials)?;
+            }
+            "disk" => {
+                Self::parse_disk(data, world, &state.materials)?;
+            }
+            "box" => {
+                Self::parse_box(data, world, &state.materials)?;
+            }
+            "cylinder" | "cone" | "capsule" => {
+                Self::parse_axial(data, world, &state.materials)?;
+            }
+            "plane" => {
+                Self::parse_plane(data, world, &state.materials)?;
+            }
+            "torus" => {
+                Self::parse_torus(data, world, &state.materials)?;
+            }
+            "tri" => {
+                Self::parse_tri(data, world, &state.materials)?;
+            }
+            "mesh" => {
+                Self::parse_mesh(data, world, &state.materials)?;
+            }
+            "model" => {
+                Self::parse_model(data, world, &state.materials, scene_path)?;
+            }
+            "instance" => {
+                Self::expect_args(data, &[3], "OBJ instance name")?;
+                let geometry = match state.definitions.get(&data[2]) {
+                    Some(_geometry) => Arc::clone(_geometry),
+                    None => return Err(LineError::new(2, SceneErrorKind::UndefinedName("geometry")))
+                };
+
+                world.objects.push(Transformed::new(geometry, state.transform.unwrap_or_else(Matrix4::identity)));
+                return Ok(());
+            }
+            _ => {
+                return Err(LineError::new(1, SceneErrorKind::UnknownType("object type")));
+            }
+        }
+
+        if let Some(matrix) = state.transform {
+            // Lights are sampled in world space, transformed lights are only found by the bounced rays
+            world.lights.truncate(light_count);
+
+            let object = world.objects.pop().unwrap();
+            world.objects.push(Transformed::new(Arc::from(object), matrix));
+        }
+
+        Ok(())
+    }
+
+    /// # `parse_transform`
+    /// Parses the TRANSLATE, ROTATE or SCALE modifier given as `Vec<String>` and returns its matrix.
+    /// SCALE can be given a single value to scale all axes by the same amount
+    fn parse_transform(data: &[String]) -> Result<Matrix4, LineError> {
+        let vector = match data[0].as_str() {
+            "SCALE" => {
+                Self::expect_args(data, &[2, 4], "SCALE x y z")?;
+                match data.len() {
+                    2 => {
+                        let scale = Self::get_val(data, 1)?;
+                        Vector3::new(scale, scale, scale)
+                    }
+                    _ => Self::get_vector(data, 1)?
+                }
+            }
+            "TRANSLATE" => {
+                Self::expect_args(data, &[4], "TRANSLATE x y z")?;
+                Self::get_vector(data, 1)?
+            }
+            _ => {
+                Self::expect_args(data, &[4], "ROTATE x y z")?;
+                Self::get_vector(data, 1)?
+            }
+        };
+
+        Ok(match data[0].as_str() {
+            "TRANSLATE" => Matrix4::translation(vector),
+            "ROTATE" => Matrix4::rotation(vector),
+            _ => Matrix4::scaling(vector)
+        })
+    }
+
+    /// # `parse_sphere`
+    /// Parses the sphere data given as `Vec<String>`. Also takes the `World` struct and `HashMap<String, Material>` to add the sphere into the world
+    fn parse_sphere(
+        data: &[String],
+        world: &mut World,
+        materials: &HashMap<String, Material>,
+    ) -> Result<(), LineError> {
+        Self::expect_args(data, &[7], "OBJ sphere materialName c1 c2 c3 r")?;
+
+        let material = Self::get_material(data, 2, materials)?;
+        let center = Self::get_vector(data, 3)?;
+        let radius = Self::get_val(data, 6)?;
+
+        // Emitting spheres are also sampled directly as lights
+        if matches!(material.material_type, MaterialType::DiffuseLight) {
+            world.lights.push(Sphere::new(center, radius, material.clone()));
+        }
+
+        world.objects.push(Sphere::new(
+            center,
+            radius,
+            material,
+        ));
+        Ok(())
+    }
+
+    /// # `parse_rect`
+    /// Parses the rectangle data given as `Vec<String>`. Also takes the `World` struct and `HashMap<String, Material>` to add the rectangle into the world
+    fn parse_rect(
+        data: &[String],
+        world: &mut World,
+        materials: &HashMap<String, Material>,
+    ) -> Result<(), LineError> {
+        Self::expect_args(data, &[9], "OBJ rect materialName axis a1 a2 b1 b2 d")?;
+
+        let material = Self::get_material(data, 2, materials)?;
+        let axis = match data[3].as_str() {
+            "xy" => Axis::XY,
+            "xz" => Axis::XZ,
+            "yz" => Axis::YZ,
+            _ => {
+                return Err(LineError::new(3, SceneErrorKind::UnknownType("axis")));
+            }
+        };
+
+        let coord1 = (Self::get_val(data, 4)?, Self::get_val(data, 5)?);
+        let coord2 = (Self::get_val(data, 6)?, Self::get_val(data, 7)?);
+        let depth = Self::get_val(data, 8)?;
+
+        // Emitting rectangles are also sampled directly as lights
+        if matches!(material.material_type, MaterialType::DiffuseLight) {
+            world.lights.push(Rectangle::new(axis, coord1, coord2, depth, material.clone()));
+        }
+
+        world.objects.push(Rectangle::new(
+            axis,
+            coord1,
+            coord2,
+            depth,
+            material,
+        ));
+        Ok(())
+    }
+
+    /// # `parse_quad`
+    /// Parses the quad data given as `Vec<String>`. Also takes the `World` struct and `HashMap<String, Material>` to add the quad into the world.
+    /// The quad is given by a corner and the two edges leaving it
+    fn parse_quad(
+        data: &[String],
+        world: &mut World,
+        materials: &HashMap<String, Material>,
+    ) -> Result<(), LineError> {
+        Self::expect_args(data, &[12], "OBJ quad materialName c1 c2 c3 u1 u2 u3 v1 v2 v3")?;
+
+        let material = Self::get_material(data, 2, materials)?;
+        let corner = Self::get_vector(data, 3)?;
+        let edge_u = Self::get_vector(data, 6)?;
+        let edge_v = Self::get_vector(data, 9)?;
+        if edge_u.cross(edge_v).norm_squared() == 0.0 {
+            return Err(LineError::new(6, SceneErrorKind::InvalidValue("the edges of the quad must not be zero or parallel".to_string())));
+        }
+
+        // Emitting quads are also sampled directly as lights
+        if matches!(material.material_type, MaterialType::DiffuseLight) {
+            world.lights.push(Quad::new(corner, edge_u, edge_v, material.clone()));
+        }
+
+        world.objects.push(Quad::new(
+            corner,
+            edge_u,
+            edge_v,
+            material,
+        ));
+        Ok(())
+    }
+
+    /// # `parse_disk`
+    /// Parses the disk data given as `Vec<String>`. Also takes the `World` struct and `HashMap<String, Material>` to add the disk into the world
+    fn parse_disk(
+        data: &[String],
+        world: &mut World,
+        materials: &HashMap<String, Material>,
+    ) -> Result<(), LineError> {
+        Self::expect_args(data, &[10], "OBJ disk materialName c1 c2 c3 n1 n2 n3 r")?;
+
+        let material = Self::get_material(data, 2, materials)?;
+        let center = Self::get_vector(data, 3)?;
+        let normal = Self::get_vector(data, 6)?;
+        let radius = Self::get_val(data, 9)?;
+        if normal.norm_squared() == 0.0 {
+            return Err(LineError::new(6, SceneErrorKind::InvalidValue("the normal of the disk must not be zero".to_string())));
+        }
+        if radius <= 0.0 {
+            return Err(LineError::new(9, SceneErrorKind::InvalidValue("the radius must be greater than 0".to_string())));
+        }
+
+        // Emitting disks are also sampled directly as lights
+        if matches!(material.material_type, MaterialType::DiffuseLight) {
+            world.lights.push(Disk::new(center, normal, radius, material.clone()));
+        }
+
+        world.objects.push(Disk::new(
+            center,
+            normal,
+            radius,
+            material,
+        ));
+        Ok(())
+    }
+
+    /// # `parse_box`
+    /// Parses the box data given as `Vec<String>`. Also takes the `World` struct and `HashMap<String, Material>` to add the box into the world.
+    /// The box is given by two opposite corners
+    fn parse_box(
+        data: &[String],
+        world: &mut World,
+        materials: &HashMap<String, Material>,
+    ) -> Result<(), LineError> {
+        Self::expect_args(data, &[9], "OBJ box materialName x1 y1 z1 x2 y2 z2")?;
+
+        let material = Self::get_material(data, 2, materials)?;
+        let corner1 = Self::get_vector(data, 3)?;
+        let corner2 = Self::get_vector(data, 6)?;
+        let min = Vector3::new(corner1.x.min(corner2.x), corner1.y.min(corner2.y), corner1.z.min(corner2.z));
+        let max = Vector3::new(corner1.x.max(corner2.x), corner1.y.max(corner2.y), corner1.z.max(corner2.z));
+        if min.x == max.x || min.y == max.y || min.z == max.z {
+            return Err(LineError::new(6, SceneErrorKind::InvalidValue("the box must have a size in every axis, use a rect or quad for flat faces".to_string())));
+        }
+
+        // Emitting boxes are also sampled directly as lights
+        if matches!(material.material_type, MaterialType::DiffuseLight) {
+            world.lights.push(Cuboid::new(min, max, material.clone()));
+        }
+
+        world.objects.push(Cuboid::new(
+            min,
+            max,
+            material,
+        ));
+        Ok(())
+    }
+
+    /// # `parse_axial`
+    /// Parses the data of a cylinder, cone or capsule given as `Vec<String>`. Also takes the `World` struct and `HashMap<String, Material>` to add the object into the world.
+    /// All three are given by the two ends of their axis and a radius, cylinders and cones can be followed by `open` to leave out their caps
+    fn parse_axial(
+        data: &[String],
+        world: &mut World,
+        materials: &HashMap<String, Material>,
+    ) -> Result<(), LineError> {
+        match data[1].as_str() {
+            "cylinder" => Self::expect_args(data, &[10, 11], "OBJ cylinder materialName x1 y1 z1 x2 y2 z2 r [open]")?,
+            "cone" => Self::expect_args(data, &[10, 11], "OBJ cone materialName b1 b2 b3 t1 t2 t3 r [open]")?,
+            _ => Self::expect_args(data, &[10], "OBJ capsule materialName x1 y1 z1 x2 y2 z2 r")?
+        }
+
+        let material = Self::get_material(data, 2, materials)?;
+        let start = Self::get_vector(data, 3)?;
+        let end = Self::get_vector(data, 6)?;
+        let radius = Self::get_val(data, 9)?;
+        if (end - start).norm_squared() == 0.0 {
+            return Err(LineError::new(6, SceneErrorKind::InvalidValue("the two ends of the axis must be different".to_string())));
+        }
+        if radius <= 0.0 {
+            return Err(LineError::new(9, SceneErrorKind::InvalidValue("the radius must be greater than 0".to_string())));
+        }
+
+        let capped = match data.get(10).map(|_flag| _flag.as_str()) {
+            None => true,
+            Some("open") => false,
+            Some(_) => return Err(LineError::new(10, SceneErrorKind::UnknownType("option")))
+        };
+
+        world.objects.push(match data[1].as_str() {
+            "cylinder" => Cylinder::new(start, end, radius, capped, material),
+            "cone" => Cone::new(start, end, radius, capped, material),
+            _ => Capsule::new(start, end, radius, material)
+        });
+        Ok(())
+    }
+
+    /// # `parse_plane`
+    /// Parses the plane data given as `Vec<String>`. Also takes the `World` struct and `HashMap<String, Material>` to add the plane into the world
+    fn parse_plane(
+        data: &[String],
+        world: &mut World,
+        materials: &HashMap<String, Material>,
+    ) -> Result<(), LineError> {
+        Self::expect_args(data, &[9], "OBJ plane materialName p1 p2 p3 n1 n2 n3")?;
+
+        let material = Self::get_material(data, 2, materials)?;
+        let point = Self::get_vector(data, 3)?;
+        let normal = Self::get_vector(data, 6)?;
+        if normal.norm_squared() == 0.0 {
+            return Err(LineError::new(6, SceneErrorKind::InvalidValue("the normal of the plane must not be zero".to_string())));
+        }
+
+        world.objects.push(Plane::new(
+            point,
+            normal,
+            material,
+        ));
+        Ok(())
+    }
+
+    /// # `parse_torus`
+    /// Parses the torus data given as `Vec<String>`. Also takes the `World` struct and `HashMap<String, Material>` to add the torus into the world
+    fn parse_torus(
+        data: &[String],
+        world: &mut World,
+        materials: &HashMap<String, Material>,
+    ) -> Result<(), LineError> {
+        Self::expect_args(data, &[11], "OBJ torus materialName c1 c2 c3 a1 a2 a3 R r")?;
+
+        let material = Self::get_material(data, 2, materials)?;
+        let center = Self::get_vector(data, 3)?;
+        let axis = Self::get_vector(data, 6)?;
+        let major_radius = Self::get_val(data, 9)?;
+        let minor_radius = Self::get_val(data, 10)?;
+        if axis.norm_squared() == 0.0 {
+            return Err(LineError::new(6, SceneErrorKind::InvalidValue("the axis of the torus must not be zero".to_string())));
+        }
+        if major_radius <= 0.0 || minor_radius <= 0.0 {
+            return Err(LineError::new(if major_radius <= 0.0 {9} else {10}, SceneErrorKind::InvalidValue("the radius must be greater than 0".to_string())));
+        }
+
+        world.objects.push(Torus::new(
+            center,
+            axis,
+            major_radius,
+            minor_radius,
+            material,
+        ));
+        Ok(())
+    }
+
+    /// # `parse_tri`
+    /// Parses the triangle data given as `Vec<String>`. Also takes the `World` struct and `HashMap<String, Material>` to add the triangle into the world.
+    /// The three vertices can be followed by three vertex normals for smooth shading
+    fn parse_tri(
+        data: &[String],
+        world: &mut World,
+        materials: &HashMap<String, Material>,
+    ) -> Result<(), LineError> {
+        Self::expect_args(data, &[12, 21], "OBJ tri materialName x1 y1 z1 x2 y2 z2 x3 y3 z3 [normals...]")?;
+
+        let material = Self::get_material(data, 2, materials)?;
+        let points = |_start: usize| -> Result<[Vector3; 3], LineError> {
+            Ok([Self::get_vector(data, _start)?, Self::get_vector(data, _start + 3)?, Self::get_vector(data, _start + 6)?])
+        };
+
+        let normals = match data.len() {
+            12 => None,
+            _ => {
+                let normals = points(12)?;
+                if let Some(_idx) = normals.iter().position(|_normal| _normal.norm_squared() == 0.0) {
+                    return Err(LineError::new(12 + 3 * _idx, SceneErrorKind::InvalidValue("a vertex normal can not have length 0".to_string())));
+                }
+                Some(normals.map(|_normal| _normal.unit()))
+            }
+        };
+
+        world.objects.push(Triangle::new(
+            points(3)?,
+            normals,
+            material,
+        ));
+        Ok(())
+    }
+
+    /// # `parse_mesh`
+    /// Parses the mesh data given as `Vec<String>`. Also takes the `World` struct and `HashMap<String, Material>` to add the mesh into the world.
+    /// The data is the amount of vertices, followed by the coordinates of every vertex, followed by the indices of the vertices of every triangle
+    fn parse_mesh(
+        data: &[String],
+        world: &mut World,
+        materials: &HashMap<String, Material>,
+    ) -> Result<(), LineError> {
+        const USAGE: &str = "OBJ mesh materialName n x1 y1 z1 ... xn yn zn a1 b1 c1 ...";
+        Self::expect_min_args(data, 4, USAGE)?;
+
+        let material = Self::get_material(data, 2, materials)?;
+        let vertex_count = Self::get_int::<usize>(data, 3)?;
+        let coords_end = 4 + 3 * vertex_count;
+
+        if data.len() <= coords_end || !(data.len() - coords_end).is_multiple_of(3) {
+            return Err(LineError::new(0, SceneErrorKind::ArgumentCount {usage: USAGE, given: data.len()}));
+        }
+
+        let vertices = (4..coords_end)
+            .step_by(3)
+            .map(|_idx| Self::get_vector(data, _idx))
+            .collect::<Result<Vec<Vector3>, LineError>>()?;
+
+        let indices = (coords_end..data.len())
+            .map(|_idx| {
+                let idx = Self::get_int::<usize>(data, _idx)?;
+                if idx >= vertex_count {
+                    return Err(LineError::new(_idx, SceneErrorKind::InvalidValue(format!("index {} out of range, the mesh has {} vertices", idx, vertex_count))));
+                }
+                Ok(idx)
+            })
+            .collect::<Result<Vec<usize>, LineError>>()?;
+
+        world.objects.push(Mesh::new(
+            vertices,
+            Vec::new(),
+            indices.chunks(3).map(|_face| [_face[0], _face[1], _face[2]]).collect(),
+            material,
+        ));
+        Ok(())
+    }
+
+    /// # `parse_model`
+    /// Parses the model data given as `Vec<String>` and loads the .obj file it points to. The path is relative to the .scene file given as `&str`.
+    /// Also takes the `World` struct and `HashMap<String, Material>` to add the model as a mesh into the world.
+    /// The path can be followed by the options `scale s`, `rotate x y z` (degrees) and `translate x y z`
+    fn parse_model(
+        data: &[String],
+        world: &mut World,
+        materials: &HashMap<String, Material>,
+        scene_path: &str,
+    ) -> Result<(), LineError> {
+        const USAGE: &str = "OBJ model materialName path [scale s] [rotate x y z] [translate x y z]";
+        Self::expect_min_args(data, 4, USAGE)?;
+
+        let material = Self::get_material(data, 2, materials)?;
+        let path = Self::relative_path(scene_path, &data[3]);
+
+        let mut scale = 1.0;
+        let mut rotation = ORIGIN;
+        let mut translation = ORIGIN;
+
+        let mut idx = 4;
+        while idx < data.len() {
+            let option_len = match data[idx].as_str() {
+                "scale" => 2,
+                "rotate" | "translate" => 4,
+                _ => return Err(LineError::new(idx, SceneErrorKind::UnknownType("model option")))
+            };
+            if idx + option_len > data.len() {
+                return Err(LineError::new(idx, SceneErrorKind::ArgumentCount {usage: USAGE, given: data.len()}));
+            }
+
+            match data[idx].as_str() {
+                "scale" => {
+                    scale = Self::get_val(data, idx + 1)?;
+                    if scale <= 0.0 {
+                        return Err(LineError::new(idx + 1, SceneErrorKind::InvalidValue("the model scale must be greater than 0".to_string())));
+                    }
+                }
+                "rotate" => {rotation = Self::get_vector(data, idx + 1)?;}
+                _ => {translation = Self::get_vector(data, idx + 1)?;}
+            }
+            idx += option_len;
+        }
+
+        let mut model = ObjModel::load(&path).map_err(|_error| LineError::new(3, SceneErrorKind::Model(_error)))?;
+        model.transform(scale, rotation, translation);
+
+        world.objects.push(Mesh::new(
+            model.vertices,
+            model.normals,
+            model.indices,
+            material,
+        ));
+        Ok(())
+    }
+
+    /// # `parse_mat`
+    /// Parses the material data given as `Vec<String>` and returns the material and its variable name as `(String, Material)`.
+    /// The colour is either given as three values or as the name of a texture in the given `HashMap<String, Arc<dyn Texture>>`
+    fn parse_mat(data: &[String], textures: &HashMap<String, Arc<dyn Texture>>) -> Result<(String, Material), LineError> {
+        Self::expect_min_args(data, 4, "MAT name type c1 c2 c3 [v]")?;
+
+        let (texture, extra_idx) = match textures.get(&data[3]) {
+            Some(_texture) => {
+                Self::expect_args(data, &[4, 5], "MAT name type texture [v]")?;
+                (Arc::clone(_texture), 4)
+            }
+            None => {
+                Self::expect_args(data, &[6, 7], "MAT name type c1 c2 c3 [v]")?;
+                (SolidColour::new(Self::get_vector(data, 3)?), 6)
+            }
+        };
+
+        // Fuzziness or index of refraction, metals can use a texture as fuzziness
+        let roughness = data.get(extra_idx).and_then(|_name| textures.get(_name));
+        let extra_val = match data.get(extra_idx) {
+            Some(_) if roughness.is_none() => Self::get_val(data, extra_idx)?,
+            _ => 1.0,
+        };
+
+        let name = data[1].to_string();
+        let mat_type = match data[2].as_str() {
+            "metal" => MaterialType::Metal(extra_val),
+            "lambertian" => MaterialType::Lambertian,
+            "dielectric" => MaterialType::Dielectric(extra_val),
+            "light" => MaterialType::DiffuseLight,
+            _ => {
+                return Err(LineError::new(2, SceneErrorKind::UnknownType("material type")));
+            }
+        };
+
+        match roughness {
+            Some(_roughness) if matches!(mat_type, MaterialType::Metal(_)) => {
+                Ok((name, Material::new(texture, mat_type).with_roughness(Arc::clone(_roughness))))
+            }
+            Some(_) => Err(LineError::new(extra_idx, SceneErrorKind::InvalidValue("only metal materials can use a texture as extra value".to_string()))),
+            None => Ok((name, Material::new(texture, mat_type)))
+        }
+    }
+
+    /// # `parse_tex`
+    /// Parses the texture data given as `Vec<String>` and returns the texture and its variable name as `(String, Arc<dyn Texture>)`.
+    /// Takes the already defined textures as `HashMap<String, Arc<dyn Texture>>` and the path of the .scene file as `&str` which image paths are relative to
+    fn parse_tex(data: &[String], textures: &HashMap<String, Arc<dyn Texture>>, scene_path: &str) -> Result<(String, Arc<dyn Texture>), LineError> {
+        Self::expect_min_args(data, 3, "TEX name type ...")?;
+
+        let name = data[1].to_string();
+        let texture_at = |_idx: usize| match textures.get(&data[_idx]) {
+            Some(_texture) => Ok(Arc::clone(_texture)),
+            None => Err(LineError::new(_idx, SceneErrorKind::UndefinedName("texture")))
+        };
+
+        let texture = match data[2].as_str() {
+            "solid" => {
+                Self::expect_args(data, &[6], "TEX name solid c1 c2 c3")?;
+                SolidColour::new(Self::get_vector(data, 3)?)
+            }
+            "checker" => {
+                Self::expect_args(data, &[6], "TEX name checker size tex1 tex2")?;
+                Checker::new(Self::get_val(data, 3)?, texture_at(4)?, texture_at(5)?)
+            }
+            "image" => {
+                Self::expect_args(data, &[4], "TEX name image path")?;
+                let path = Self::relative_path(scene_path, &data[3]);
+
+                ImageTexture::load(&path).map_err(|_error| LineError::new(3, SceneErrorKind::Image(_error.to_string())))?
+            }
+            "noise" | "turbulence" | "marble" | "wood" => {
+                Self::expect_args(data, &[5, 7], "TEX name pattern scale seed [tex1 tex2]")?;
+                let pattern = match data[2].as_str() {
+                    "noise" => NoisePattern::Noise,
+                    "turbulence" => NoisePattern::Turbulence,
+                    "marble" => NoisePattern::Marble,
+                    _ => NoisePattern::Wood
+                };
+                let scale = Self::get_val(data, 3)?;
+                let seed = Self::get_int::<u64>(data, 4)?;
+
+                // Blend from black to white if no textures are given
+                let (low, high) = match data.len() {
+                    5 => (SolidColour::new(ORIGIN), SolidColour::new(Colour::new(1.0, 1.0, 1.0))),
+                    _ => (texture_at(5)?, texture_at(6)?)
+                };
+
+                NoiseTexture::new(pattern, scale, seed, low, high)
+            }
+            _ => {
+                return Err(LineError::new(2, SceneErrorKind::UnknownType("texture type")));
+            }
+        };
+
+        Ok((name, texture))
+    }
+
+    /// # `tokenize`
+    /// Splits a line at whitespace and returns the tokens with the column (starting at 1) they start at as `Vec<(usize, &str)>`
+    fn tokenize(line: &str) -> Vec<(usize, &str)> {
+        let mut tokens = Vec::new();
+        let mut start: Option<usize> = None; // Byte index of the current token
+
+        for (_idx, _char) in line.char_indices().chain([(line.len(), ' ')]) {
+            match (start, _char.is_whitespace()) {
+                (None, false) => start = Some(_idx),
+                (Some(_start), true) => {
+                    tokens.push((line[.._start].chars().count() + 1, &line[_start.._idx]));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+
+        tokens
+    }
+
+    /// # `referenced_file`
+    /// Returns the path of the file read by the given line as `Vec<String>` if it loads a model or an image texture
+    fn referenced_file(data: &[String]) -> Option<&str> {
+        let path_idx = match (data[0].as_str(), data.get(1).map(String::as_str), data.get(2).map(String::as_str)) {
+            ("OBJ", Some("model"), _) => 3,
+            ("DEF", _, Some("model")) => 4,
+            ("TEX", _, Some("image")) => 3,
+            _ => return None
+        };
+        data.get(path_idx).map(String::as_str)
+    }
+
+    /// # `relative_path`
+    /// Returns the given path as `&str` relative to the directory of the .scene file
+    fn relative_path(scene_path: &str, path: &str) -> PathBuf {
+        Path::new(scene_path)
+            .parent()
+            .unwrap_or_else(|| Path::new(""))
+            .join(path)
+    }
+
+    /// # `expect_args`
+    /// Returns an error if the amount of tokens in the line is not one of the given amounts. `usage` is shown in the error
+    fn expect_args(data: &[String], allowed: &[usize], usage: &'static str) -> Result<(), LineError> {
+        if allowed.contains(&data.len()) {
+            return Ok(());
+        }
+
+        // Point at the first extra token if there are too many, otherwise at the command
+        let token = if data.len() > *allowed.iter().max().unwrap() {*allowed.iter().max().unwrap()} else {0};
+        Err(LineError::new(token, SceneErrorKind::ArgumentCount {usage, given: data.len()}))
+    }
+
+    /// # `expect_min_args`
+    /// Returns an error if the line has less tokens than the given amount. `usage` is shown in the error
+    fn expect_min_args(data: &[String], min: usize, usage: &'static str) -> Result<(), LineError> {
+        if data.len() < min {
+            return Err(LineError::new(0, SceneErrorKind::ArgumentCount {usage, given: data.len()}));
+        }
+
+        Ok(())
+    }
+
+    /// # `get_material`
+    /// Returns a copy of the material whose name is the token at the given index
+    fn get_material(data: &[String], idx: usize, materials: &HashMap<String, Material>) -> Result<Material, LineError> {
+        match materials.get(&data[idx]) {
+            Some(_material) => Ok(_material.clone()),
+            None => Err(LineError::new(idx, SceneErrorKind::UndefinedName("material")))
+        }
+    }
+
+    /// # `get_vector`
+    /// Parses the three tokens starting at the given index into a `Vector3` using `get_val`
+    fn get_vector(data: &[String], idx: usize) -> Result<Vector3, LineError> {
+        Ok(Vector3::new(Self::get_val(data, idx)?, Self::get_val(data, idx + 1)?, Self::get_val(data, idx + 2)?))
+    }
+
+    /// # `get_int`
+    /// Parses the token at the given index into a whole number
+    fn get_int<T: FromStr>(data: &[String], idx: usize) -> Result<T, LineError> {
+        data[idx].parse::<T>().map_err(|_| LineError::new(idx, SceneErrorKind::BadNumber))
+    }
+
+    /// # `get_val`
+    /// Parses the token at the given index to f32. If the format `x_y` is given then a random f32 is generated in the range `[x, y]` from the seed of the parser
+    fn get_val(data: &[String], idx: usize) -> Result<f32, LineError> {
+        let vals = data[idx]
+            .split('_')
+            .map(|_val| _val.parse::<f32>())
+            .collect::<Result<Vec<f32>, _>>()
+            .map_err(|_| LineError::new(idx, if data[idx].contains('_') {SceneErrorKind::BadRange} else {SceneErrorKind::BadNumber}))?;
+
+        match vals.len() {
+            1 => Ok(vals[0]),
+            2 if vals[0] <= vals[1] => Ok(random_range(vals[0], vals[1])),
+            _ => Err(LineError::new(idx, SceneErrorKind::BadRange)),
+        }
+    }
+}
@@ -1,97 +1,387 @@
-use crate::utils::{Vector3, Image, IMG_WIDTH, IMG_HEIGHT, Colour, ORIGIN, random_f32, Y_UNIT};
+use crate::utils::{Vector3, Image, Film, SampleBuffer, TILE_SIZE, Colour, ORIGIN, random_f32, seed_random, Y_UNIT};
 use super::ray::Ray;
 use super::objects::{World};
-use std::time::Instant;
+use super::integrators::{Integrator, NormalShading};
+use std::io::{self, Write};
+use std::sync::{atomic::{AtomicUsize, Ordering}, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Samples every pixel gets before its error is trusted by adaptive sampling
+const ADAPTIVE_MIN_SAMPLES: usize = 16;
+// Samples per pass of an adaptive render when no progressive passes are given
+const ADAPTIVE_PASS_SAMPLES: usize = 8;
+// Samples per pass and least time between saves of a checkpointed render, so a stopped render loses at most the samples since the last save
+const CHECKPOINT_PASS_SAMPLES: usize = 8;
+const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// # `Tile`
+/// A rectangular block of the image that is rendered by a single thread. Holds the pixel range of the block and its rendered pixels row by row
+struct Tile<T> {
+    x: usize,
+    y: usize,
+    x_end: usize,
+    y_end: usize,
+    pixels: Vec<T>
+}
+
+impl<T> Tile<T> {
+    /// # `new`
+    /// Creates a new empty tile whose top left pixel is at the given x and y as `usize`. The tile is cut off at the given width and height of the image
+    fn new(x: usize, y: usize, width: usize, height: usize) -> Tile<T> {
+        Tile {
+            x,
+            y,
+            x_end: (x + TILE_SIZE).min(width),
+            y_end: (y + TILE_SIZE).min(height),
+            pixels: Vec::with_capacity(TILE_SIZE * TILE_SIZE)
+        }
+    }
+}
+
+/// # `Progressive`
+/// Settings of a progressive render, the amount of samples per pixel in every pass over the image and the least time between two saved snapshots
+#[derive(Clone, Copy)]
+pub struct Progressive {
+    pub pass_samples: usize,
+    pub interval: Duration
+}
+
+/// # `Checkpoint`
+/// The file the samples of a render are saved to so that the render can be resumed, and the hash of the scene the samples belong to
+pub struct Checkpoint {
+    pub path: String,
+    pub scene_hash: u64
+}
+
+/// # `RenderSettings`
+/// Settings of a render, the amount of samples per pixel, the amount of threads and the optional progressive passes and checkpoint file.
+/// With an adaptive error threshold pixels stop sampling once their estimated relative error is below it, `samples` is then the most samples a pixel can get.
+/// `heatmap` saves an image of the samples spent on every pixel next to the output.
+/// Every sample of every pixel gets its own random stream of the seed, so the same seed gives the same image on any amount of threads
+pub struct RenderSettings {
+    pub samples: usize,
+    pub threads: usize,
+    pub progressive: Option<Progressive>,
+    pub checkpoint: Option<Checkpoint>,
+    pub adaptive: Option<f32>,
+    pub heatmap: bool,
+    pub seed: u64
+}
 
 /// # `Camera`
 /// Structure that stores and handles the perspective from which the scene is rendered
 pub struct Camera {
     pub origin: Vector3,
+    target: Vector3,
+    vertical_fov: f32,
+    aperture: f32,
+    focus_distance: f32,
+    width: usize,
+    height: usize,
     horizontal: Vector3,
     vertical: Vector3,
     lower_left_corner: Vector3,
+    u: Vector3,
+    v: Vector3,
+    lens_radius: f32,
+    film: Film,
     image: Image
 }
 
 impl Camera {
     /// # `new`
-    /// Creates a new Camera by taking its origin as `Vector3`, width and height of the viewport, both as `f32` and the focal length as `f32`
-    pub fn new(from: Vector3, at: Vector3, vertical_fov: f32, aspect_ratio: f32) -> Camera {
+    /// Creates a new Camera by taking its origin and target as `Vector3`, the vertical field of view in degrees as `f32` and the width and height of the image in pixels as `usize`.
+    /// The aspect ratio of the viewport follows from the resolution.
+    /// The aperture (diameter of the lens) and the focus distance as `f32` give the depth of field, an aperture of 0 is a pinhole camera where everything is sharp
+    pub fn new(from: Vector3, at: Vector3, vertical_fov: f32, width: usize, height: usize, aperture: f32, focus_distance: f32) -> Camera {
+        let aspect_ratio = width as f32 / height as f32;
         let theta = vertical_fov.to_radians();
-        let height = (theta * 0.5).tan();
-        let viewport_height = 2.0 * height;
+        let half_height = (theta * 0.5).tan();
+        let viewport_height = 2.0 * half_height;
         let viewport_width = aspect_ratio * viewport_height;
 
         let w = (from - at).unit();
         let u = Y_UNIT.cross(w).unit(); // Y_UNIT is viewport's up direction
         let v = w.cross(u);
 
-        let horizontal =  viewport_width * u;
-        let vertical =  viewport_height * v;
+        // The viewport is placed on the plane in focus
+        let horizontal = focus_distance * viewport_width * u;
+        let vertical = focus_distance * viewport_height * v;
 
         Camera {
             origin: from,
-            horizontal: horizontal,
-            vertical: vertical,
-            lower_left_corner: from - horizontal / 2.0 - vertical / 2.0 - w,
-            image: Image::new(IMG_WIDTH, IMG_HEIGHT)
+            target: at,
+            vertical_fov,
+            aperture,
+            focus_distance,
+            width,
+            height,
+            horizontal,
+            vertical,
+            lower_left_corner: from - horizontal / 2.0 - vertical / 2.0 - focus_distance * w,
+            u,
+            v,
+            lens_radius: aperture / 2.0,
+            film: Film::default(),
+            image: Image::new(width, height)
         }
     }
 
+    /// # `set_resolution`
+    /// Changes the width and height of the rendered image in pixels as `usize`. The viewport is changed to the new aspect ratio
+    pub fn set_resolution(&mut self, width: usize, height: usize) {
+        let film = self.film;
+        *self = Camera::new(self.origin, self.target, self.vertical_fov, width, height, self.aperture, self.focus_distance);
+        self.film = film;
+    }
+
+    /// # `set_film`
+    /// Changes the `Film` that turns the rendered light into the colours of the saved image
+    pub fn set_film(&mut self, film: Film) {
+        self.film = film;
+    }
+
+    /// # `film`
+    /// Returns the `Film` the rendered image is developed with
+    pub fn film(&self) -> Film {
+        self.film
+    }
+
+    /// # `resolution`
+    /// Returns the width and height of the rendered image in pixels as `(usize, usize)`
+    pub fn resolution(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
     /// # `render`
-    /// Renders the scene and saves the output as a .png file
-    pub fn render(&mut self, world: &mut World, name: &str, samples: usize, max_bounce: usize) -> std::io::Result<()> {
-        let sample_scale = 1.0 / samples as f32;
+    /// Renders the scene with the given `Integrator` and `RenderSettings` and saves the output. The format is given by the extension of the name (.png if none is given).
+    /// With `Progressive` settings the samples are split into passes over the whole image and the running average is saved between the passes.
+    /// With a `Checkpoint` the samples of an earlier render of the same scene are loaded and only the missing samples are rendered. The samples are saved back in passes every `CHECKPOINT_INTERVAL`.
+    /// With adaptive sampling every pass only samples the pixels whose error is still above the threshold
+    pub fn render(&mut self, world: &World, integrator: &dyn Integrator, name: &str, settings: &RenderSettings) -> std::io::Result<()> {
         let start = Instant::now();
-        for j in 0..IMG_HEIGHT {
-            print!("\rProgress: {}/{}", j, IMG_HEIGHT);
-            for i in 0..IMG_WIDTH {
+        let output_name = format!("images/{}", name);
+
+        let mut buffer = match &settings.checkpoint {
+            Some(_checkpoint) => self.load_checkpoint(_checkpoint),
+            None => SampleBuffer::new(self.width, self.height, 0)
+        };
+
+        let remaining = settings.samples.saturating_sub(buffer.min_count());
+        let pass_samples = match (settings.progressive, settings.adaptive) {
+            (Some(_progressive), _) => _progressive.pass_samples,
+            (None, Some(_)) => ADAPTIVE_PASS_SAMPLES,
+            (None, None) if settings.checkpoint.is_some() => CHECKPOINT_PASS_SAMPLES,
+            (None, None) => remaining
+        }.max(1);
+        let passes = remaining.div_ceil(pass_samples);
+        let mut plan = Self::plan_pass(&buffer, settings, pass_samples);
+        let mut pass = 0;
+        let mut last_snapshot = Instant::now();
+        let mut last_checkpoint = Instant::now();
+
+        while plan.iter().any(|_samples| *_samples > 0) {
+            let pass_pixels = Self::render_tiles(self.width, self.height, settings.threads, |i, j| {
                 let mut colour: Colour = ORIGIN; // black
-                for _ in 0..samples {
-                    let u = (i as f32 + random_f32()) / (IMG_WIDTH as f32 - 1.0);
-                    let v = (j as f32 + random_f32()) / (IMG_HEIGHT as f32 - 1.0);
+                let mut squares = 0.0;
+                let idx = j * self.width + i;
+                let samples = plan[idx];
+                for sample in buffer.counts[idx]..buffer.counts[idx] + samples {
+                    seed_random(settings.seed, (idx as u64) << 32 | sample as u64);
+                    // A random point inside the pixel, the pixels cover the whole screen from 0 to 1
+                    let u = (i as f32 + random_f32()) / self.width as f32;
+                    let v = (j as f32 + random_f32()) / self.height as f32;
                     let r = &self.get_ray(u, v);
-                    colour += Ray::colour(r, &world, max_bounce);
+                    let sample = integrator.colour(r, world);
+                    colour += sample;
+                    squares += Colour::luminance(&sample).powi(2);
+                }
+
+                (colour, squares, samples)
+            });
+
+            for (idx, (colour, squares, samples)) in pass_pixels.into_iter().enumerate() {
+                buffer.sums[idx] += colour;
+                buffer.squares[idx] += squares;
+                buffer.counts[idx] += samples;
+            }
+            pass += 1;
+            plan = Self::plan_pass(&buffer, settings, pass_samples);
+            let sampling = plan.iter().filter(|_samples| **_samples > 0).count();
+
+            if settings.adaptive.is_some() {
+                println!(" Pass {} done, {} pixels still sampling", pass, sampling);
+            } else if settings.progressive.is_some() || settings.checkpoint.is_some() {
+                println!(" Pass {}/{} done, {}/{} samples", pass, passes, buffer.min_count(), settings.samples);
+            }
+
+            // Save the running average of the finished passes
+            if let Some(_progressive) = settings.progressive {
+                if sampling > 0 && last_snapshot.elapsed() >= _progressive.interval {
+                    self.image = buffer.average();
+                    self.image.save(output_name.to_string(), &self.film)?;
+                    last_snapshot = Instant::now();
+                }
+            }
+
+            // Save the samples so far to the checkpoint, independent of the snapshots
+            if let Some(_checkpoint) = &settings.checkpoint {
+                if sampling > 0 && last_checkpoint.elapsed() >= CHECKPOINT_INTERVAL {
+                    buffer.save(&_checkpoint.path)?;
+                    last_checkpoint = Instant::now();
                 }
-                
-                colour = Colour::clamp(&Colour::new((colour.x * sample_scale as f32).sqrt(), (colour.y * sample_scale as f32).sqrt(), (colour.z * sample_scale as f32).sqrt()));
-                let pix = self.image.at(i, j);
-                *pix = colour;
             }
         }
 
+        self.image = buffer.average();
         println!("\nRender Finished. Took: {}s", start.elapsed().as_secs());
-        self.image.save(format!("images/{}", name).to_string())
+        if settings.adaptive.is_some() {
+            println!("Average samples per pixel: {:.1}", buffer.mean_count());
+        }
+        if let Some(_checkpoint) = &settings.checkpoint {
+            buffer.save(&_checkpoint.path)?;
+        }
+        if settings.heatmap {
+            let (stem, _) = split_extension(&output_name);
+            buffer.heatmap(settings.samples).save(format!("{}_samples.png", stem), &Film::default())?;
+        }
+        self.image.save(output_name, &self.film)
     }
 
-    /// # `fast_render`
-    /// Renders a preview of the scene as a .png file
-    pub fn fast_render(&mut self, world: &mut World, name: &str) -> std::io::Result<()> {
-        let sample_scale = 0.1f32;
-        for j in 0..IMG_HEIGHT {
-            for i in 0..IMG_WIDTH {
-                let mut colour: Colour = ORIGIN; // black
-                for _ in 0..10 {
-                    let u = (i as f32 + random_f32()) / (IMG_WIDTH as f32 - 1.0);
-                    let v = (j as f32 + random_f32()) / (IMG_HEIGHT as f32 - 1.0);
-                    let r = &self.get_ray(u, v);
-                    colour += Ray::fast_colour(r, &world);
-                }
-                
-                colour = Colour::clamp(&Colour::new((colour.x * sample_scale as f32).sqrt(), (colour.y * sample_scale as f32).sqrt(), (colour.z * sample_scale as f32).sqrt()));
+    /// # `plan_pass`
+    /// Returns how many samples every pixel gets in the next pass. A pixel is done once it has `samples` samples or, with adaptive sampling,
+    /// once it has at least `ADAPTIVE_MIN_SAMPLES` samples and its error is below the threshold. Pixels below the minimum are filled up to it first
+    fn plan_pass(buffer: &SampleBuffer, settings: &RenderSettings, pass_samples: usize) -> Vec<u32> {
+        let min_samples = settings.adaptive.map_or(0, |_| ADAPTIVE_MIN_SAMPLES);
 
-                let pix = self.image.at(i, j);
-                *pix = colour;
+        buffer.counts.iter().enumerate().map(|(_idx, _count)| {
+            let count = *_count as usize;
+            let converged = settings.adaptive.is_some_and(|_threshold| count >= min_samples && buffer.error(_idx) < _threshold);
+            if count >= settings.samples || converged {
+                return 0;
+            }
+
+            let samples = if count < min_samples {min_samples - count} else {pass_samples};
+            samples.min(settings.samples - count) as u32
+        }).collect()
+    }
+
+    /// # `load_checkpoint`
+    /// Returns the samples stored in the file of the given `Checkpoint` or an empty buffer if there is no such file or it belongs to another scene or resolution
+    fn load_checkpoint(&self, checkpoint: &Checkpoint) -> SampleBuffer {
+        match SampleBuffer::load(&checkpoint.path, self.width, self.height, checkpoint.scene_hash) {
+            Ok(_buffer) => {
+                println!("Resuming from {} with {} samples", checkpoint.path, _buffer.min_count());
+                _buffer
+            }
+            Err(_error) => {
+                if _error.kind() != std::io::ErrorKind::NotFound {
+                    println!("Could not resume from checkpoint {} ({}), starting over", checkpoint.path, _error);
+                }
+                SampleBuffer::new(self.width, self.height, checkpoint.scene_hash)
             }
         }
+    }
+
+    /// # `fast_render`
+    /// Renders a preview of the scene with normal shading and 10 samples on the given amount of threads with the given seed as `u64`. `_preview` is added to the name before the extension
+    pub fn fast_render(&mut self, world: &World, name: &str, threads: usize, seed: u64) -> std::io::Result<()> {
+        let film = self.film;
+        self.film = Film::default();
+
+        let preview_name = match split_extension(name) {
+            (_stem, Some(_ext)) => format!("{}_preview.{}", _stem, _ext),
+            (_stem, None) => format!("{}_preview", _stem)
+        };
+        let settings = RenderSettings {
+            samples: 10,
+            threads,
+            progressive: None,
+            checkpoint: None,
+            adaptive: None,
+            heatmap: false,
+            seed
+        };
+        let result = self.render(world, NormalShading::new().as_ref(), &preview_name, &settings);
 
-        println!("Preview finished!");
-        self.image.save(format!("images/{}_preview", name).to_string())
+        self.film = film;
+        result
+    }
+
+    /// # `render_tiles`
+    /// Splits an image of the given width and height into tiles of `TILE_SIZE` x `TILE_SIZE` pixels and renders them in parallel on the given amount of threads.
+    /// Every pixel (i, j) gets its value from the given `shade` function. The finished tiles are written into the returned pixels row by row
+    fn render_tiles<T, F>(width: usize, height: usize, threads: usize, shade: F) -> Vec<T>
+    where T: Clone + Default + Send, F: Fn(usize, usize) -> T + Sync {
+        let tiles_x = width.div_ceil(TILE_SIZE);
+        let tiles_y = height.div_ceil(TILE_SIZE);
+        let tile_count = tiles_x * tiles_y;
+
+        let next_tile = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel::<Tile<T>>();
+        let mut pixels = vec![T::default(); width * height];
+
+        thread::scope(|scope| {
+            for _ in 0..threads.max(1) {
+                let sender = sender.clone();
+                let next_tile = &next_tile;
+                let shade = &shade;
+
+                scope.spawn(move || loop {
+                    // Each worker takes the next unrendered tile until there are none left
+                    let idx = next_tile.fetch_add(1, Ordering::Relaxed);
+                    if idx >= tile_count {
+                        break;
+                    }
+
+                    let mut tile = Tile::new(idx % tiles_x * TILE_SIZE, idx / tiles_x * TILE_SIZE, width, height);
+                    for j in tile.y..tile.y_end {
+                        for i in tile.x..tile.x_end {
+                            tile.pixels.push(shade(i, j));
+                        }
+                    }
+
+                    if sender.send(tile).is_err() {
+                        break;
+                    }
+                });
+            }
+            drop(sender);
+
+            // Write the tiles into the image as they finish
+            for (done, tile) in receiver.iter().enumerate() {
+                print!("\rProgress: {}/{} tiles", done + 1, tile_count);
+                io::stdout().flush().ok();
+
+                let mut tile_pixels = tile.pixels.into_iter();
+                for j in tile.y..tile.y_end {
+                    for i in tile.x..tile.x_end {
+                        pixels[j * width + i] = tile_pixels.next().unwrap();
+                    }
+                }
+            }
+        });
+
+        pixels
     }
 
     /// # `get_ray`
-    /// Returns the `Ray` object at the given pixel coordinates u and v as `f32`
+    /// Returns the `Ray` object at the given pixel coordinates u and v as `f32`. The ray starts at a random point of the lens
     pub fn get_ray(&self, u: f32, v: f32) -> Ray {
-        Ray::new(self.origin, self.lower_left_corner + u * self.horizontal + v * self.vertical - self.origin)
+        // Start the ray at a random point on the lens (only the origin for a pinhole camera)
+        let lens = self.lens_radius * Vector3::random_in_unit_disk();
+        let offset = lens.x * self.u + lens.y * self.v;
+
+        Ray::new(self.origin + offset, self.lower_left_corner + u * self.horizontal + v * self.vertical - self.origin - offset)
+    }
+}
+
+/// # `split_extension`
+/// Splits the given file name as `&str` into the name without the extension and the extension, if there is one
+fn split_extension(name: &str) -> (&str, Option<&str>) {
+    match name.rsplit_once('.') {
+        Some((_stem, _ext)) if !_ext.contains('/') => (_stem, Some(_ext)),
+        _ => (name, None)
     }
 }
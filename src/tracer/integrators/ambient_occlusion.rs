@@ -0,0 +1,44 @@
+use super::{Integrator, Ray, World, HitRecord, Object};
+use crate::utils::{Colour, Vector3, INFINITY, ORIGIN, EPSILON};
+
+/// # `AmbientOcclusion`
+/// Integrator that shades each hit by how much of the hemisphere above it is open, ignoring all materials and lights.
+/// Only objects closer than the given distance block the hemisphere
+pub struct AmbientOcclusion {
+    distance: f32
+}
+
+impl AmbientOcclusion {
+    /// # `new`
+    /// Creates a new ambient occlusion integrator where objects within the given distance as `f32` block the light
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(distance: f32) -> Box<dyn Integrator> {
+        Box::new(AmbientOcclusion {
+            distance
+        })
+    }
+}
+
+impl Integrator for AmbientOcclusion {
+    /// # `colour`
+    /// Returns white if a random (cosine weighted) ray from the hit escapes the given distance, otherwise black. Missing everything is also white
+    fn colour(&self, ray: &Ray, world: &World) -> Colour {
+        let mut hit_rec = HitRecord::new_empty();
+
+        if !world.hit(ray, EPSILON, INFINITY, &mut hit_rec) {
+            return Colour::new(1.0, 1.0, 1.0);
+        }
+
+        let mut direction = hit_rec.normal + Vector3::random_in_unit_sphere().unit();
+        if direction.near_zero() {
+            direction = hit_rec.normal;
+        }
+
+        let mut occluder = HitRecord::new_empty();
+        if world.hit(&Ray::new(hit_rec.origin, direction.unit()), EPSILON, self.distance, &mut occluder) {
+            return ORIGIN;
+        }
+
+        Colour::new(1.0, 1.0, 1.0)
+    }
+}
@@ -0,0 +1,35 @@
+use super::{Ray, World, HitRecord, Object};
+use crate::utils::Colour;
+
+/// # `Integrator`
+/// Trait used for the algorithms that compute the colour seen along a camera ray. Integrators are shared between the render threads, hence `Send + Sync`
+pub trait Integrator: Send + Sync {
+    /// # `colour`
+    /// Returns the colour of the given `Ray` traced through the `World`
+    fn colour(&self, ray: &Ray, world: &World) -> Colour;
+}
+
+mod path;
+mod normal;
+mod whitted;
+mod ambient_occlusion;
+
+pub use self::{
+    path::PathTracer,
+    normal::NormalShading,
+    whitted::Whitted,
+    ambient_occlusion::AmbientOcclusion
+};
+
+/// # `from_name`
+/// Returns the integrator with the given name as `&str` (`path`, `normal`, `whitted` or `ao`) that follows rays for at most the given amount of bounces as `usize`,
+/// `None` if there is no such integrator
+pub fn from_name(name: &str, max_bounce: usize) -> Option<Box<dyn Integrator>> {
+    match name {
+        "path" => Some(PathTracer::new(max_bounce)),
+        "normal" => Some(NormalShading::new()),
+        "whitted" => Some(Whitted::new(max_bounce)),
+        "ao" => Some(AmbientOcclusion::new(crate::utils::AO_DISTANCE)),
+        _ => None
+    }
+}
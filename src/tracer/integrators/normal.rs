@@ -0,0 +1,31 @@
+use super::{Integrator, Ray, World, HitRecord, Object};
+use crate::utils::{Colour, INFINITY, EPSILON};
+
+/// # `NormalShading`
+/// Integrator that colours each object based on its normal where the ray hits it, used for fast previews of the scene
+pub struct NormalShading;
+
+impl NormalShading {
+    /// # `new`
+    /// Creates a new normal shading integrator
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> Box<dyn Integrator> {
+        Box::new(NormalShading)
+    }
+}
+
+impl Integrator for NormalShading {
+    /// # `colour`
+    /// Returns the normal of the hit mapped into colours or a sky gradient if nothing is hit
+    fn colour(&self, ray: &Ray, world: &World) -> Colour {
+        let mut hit_rec = HitRecord::new_empty();
+
+        if world.hit(ray, EPSILON, INFINITY, &mut hit_rec) {
+            return 0.5 * (hit_rec.normal + Colour::new(1.0, 1.0, 1.0))
+        }
+
+        let unit_dir = ray.direction.unit();
+        let t = 0.5 * (unit_dir.y + 1.0);
+        (1.0 - t)*Colour::new(1.0, 1.0, 1.0) + t * Colour::new(0.5, 0.7, 1.0)
+    }
+}
@@ -0,0 +1,125 @@
+use super::{Integrator, Ray, World, HitRecord, Object};
+use crate::utils::{Colour, random_f32, INFINITY, ORIGIN, EPSILON};
+
+// Amount of bounces before paths can be ended by Russian roulette
+const ROULETTE_DEPTH: usize = 3;
+
+/// # `PathTracer`
+/// Integrator that follows the rays as they bounce around the world, sampling the lights directly at every non-specular hit.
+/// The light and material samples are combined with multiple importance sampling and paths carrying little light are ended early by Russian roulette
+pub struct PathTracer {
+    max_bounce: usize
+}
+
+impl PathTracer {
+    /// # `new`
+    /// Creates a new path tracer that follows the rays for at most the given amount of bounces as `usize`
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(max_bounce: usize) -> Box<dyn Integrator> {
+        Box::new(PathTracer {
+            max_bounce
+        })
+    }
+
+    /// # `trace`
+    /// Returns the colour of the ray traced through the world for at most the maximum amount of bounces.
+    /// The path is followed in a loop carrying its throughput (how much of the light found further along reaches the camera).
+    /// After `ROULETTE_DEPTH` bounces the path is ended at random with a chance based on the throughput, surviving paths are brightened to make up for it
+    fn trace(&self, ray: &Ray, world: &World) -> Colour {
+        let mut colour = ORIGIN; // black
+        let mut throughput = Colour::new(1.0, 1.0, 1.0);
+        let mut ray = Ray::new(ray.origin, ray.direction);
+        let mut bsdf_pdf: Option<f32> = None; // Probability density of the last bounce, None if no light was sampled there
+
+        for depth in 0..self.max_bounce {
+            let mut hit_rec = HitRecord::new_empty();
+            if !world.hit(&ray, EPSILON, INFINITY, &mut hit_rec) {
+                colour += throughput * world.background;
+                break;
+            }
+
+            let material = hit_rec.material.unwrap();
+            let mut emitted = material.emit(&hit_rec);
+
+            // The light could also have been found by sampling the lights at the origin of the ray
+            match bsdf_pdf {
+                Some(_pdf) if emitted.x + emitted.y + emitted.z > 0.0 => {
+                    emitted *= power_heuristic(_pdf, world.light_pdf(&ray.origin, &ray.direction));
+                }
+                _ => {}
+            }
+            colour += throughput * emitted;
+
+            // The lights are sampled before the material, so the direct light is kept even if the material gives no bounce
+            let sample_lights = !material.is_specular(&hit_rec) && !world.lights.is_empty();
+            if sample_lights {
+                colour += throughput * PathTracer::direct_light(&ray, &hit_rec, world);
+            }
+
+            let scatter = match material.sample(&ray, &hit_rec) {
+                Some(_scatter) => _scatter,
+                None => break
+            };
+            bsdf_pdf = if sample_lights {scatter.pdf} else {None};
+            throughput = throughput * scatter.attenuation;
+
+            // Russian roulette
+            if depth + 1 >= ROULETTE_DEPTH {
+                let survival = throughput.x.max(throughput.y).max(throughput.z).min(0.95);
+                if random_f32() >= survival {
+                    break;
+                }
+                throughput /= survival;
+            }
+
+            ray = scatter.ray;
+        }
+
+        // A single broken sample would stay in the pixel average (and its checkpoint) for good, so it is dropped
+        if colour.is_finite() {colour} else {ORIGIN}
+    }
+
+    /// # `direct_light`
+    /// Returns the light arriving directly from a randomly sampled light at the hit given by `HitRecord` of the given `Ray`.
+    /// A shadow ray is cast towards the light which only counts if it reaches an emitting surface
+    fn direct_light(ray: &Ray, hit_rec: &HitRecord, world: &World) -> Colour {
+        let direction = match world.sample_light(&hit_rec.origin) {
+            Some(_direction) => _direction,
+            None => return ORIGIN
+        };
+
+        let material = hit_rec.material.unwrap();
+        let light_pdf = world.light_pdf(&hit_rec.origin, &direction);
+        let bsdf = material.eval(ray, hit_rec, &direction);
+        if light_pdf <= 0.0 || !light_pdf.is_finite() || bsdf.x + bsdf.y + bsdf.z <= 0.0 {
+            return ORIGIN;
+        }
+
+        let mut light_rec = HitRecord::new_empty();
+        if !world.hit(&Ray::new(hit_rec.origin, direction), EPSILON, INFINITY, &mut light_rec) {
+            return ORIGIN;
+        }
+
+        let weight = power_heuristic(light_pdf, material.pdf(ray, hit_rec, &direction));
+        bsdf * light_rec.material.unwrap().emit(&light_rec) * (weight / light_pdf)
+    }
+}
+
+impl Integrator for PathTracer {
+    /// # `colour`
+    /// Returns the colour of the ray found by following it for at most the maximum amount of bounces
+    fn colour(&self, ray: &Ray, world: &World) -> Colour {
+        self.trace(ray, world)
+    }
+}
+
+/// # `power_heuristic`
+/// Returns the weight of a sample picked with the probability density `pdf` when the other strategy could have picked it with `other_pdf`
+fn power_heuristic(pdf: f32, other_pdf: f32) -> f32 {
+    let pdf_squared = pdf * pdf;
+    if pdf_squared + other_pdf * other_pdf <= 0.0 {
+        return 1.0;
+    }
+
+    pdf_squared / (pdf_squared + other_pdf * other_pdf)
+}
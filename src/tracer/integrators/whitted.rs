@@ -0,0 +1,86 @@
+use super::{Integrator, Ray, World, HitRecord, Object};
+use crate::utils::{Colour, INFINITY, ORIGIN, EPSILON};
+
+// Relative distance in front of the sampled light in which other hits are still counted as the light itself
+const SHADOW_TOLERANCE: f32 = 1.0e-4;
+
+/// # `Whitted`
+/// Integrator that only follows mirror reflections and refractions. Other surfaces are lit by sampling every light once without any light bouncing between them
+pub struct Whitted {
+    max_bounce: usize
+}
+
+impl Whitted {
+    /// # `new`
+    /// Creates a new Whitted-style integrator that follows specular rays for at most the given amount of bounces as `usize`
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(max_bounce: usize) -> Box<dyn Integrator> {
+        Box::new(Whitted {
+            max_bounce
+        })
+    }
+
+    /// # `trace`
+    /// Returns the colour of the ray traced through the world for at most depth specular bounces as `usize`
+    fn trace(ray: &Ray, world: &World, depth: usize) -> Colour {
+        let mut hit_rec = HitRecord::new_empty();
+
+        if depth == 0 {
+            return ORIGIN; // black colour
+        }
+
+        if !world.hit(ray, EPSILON, INFINITY, &mut hit_rec) {
+            return world.background;
+        }
+
+        let material = hit_rec.material.unwrap();
+        let emitted = material.emit(&hit_rec);
+
+        if material.is_specular(&hit_rec) {
+            return match material.sample(ray, &hit_rec) {
+                Some(_scatter) => emitted + _scatter.attenuation * Whitted::trace(&_scatter.ray, world, depth - 1),
+                None => emitted
+            };
+        }
+
+        emitted + Whitted::direct_light(ray, &hit_rec, world)
+    }
+
+    /// # `direct_light`
+    /// Returns the light arriving directly from all the lights of the world at the hit given by `HitRecord` of the given `Ray`, using one shadow ray per light
+    fn direct_light(ray: &Ray, hit_rec: &HitRecord, world: &World) -> Colour {
+        let material = hit_rec.material.unwrap();
+        let mut colour = ORIGIN;
+
+        for light in world.lights.iter() {
+            let direction = light.random(&hit_rec.origin);
+            let pdf = light.pdf_value(&hit_rec.origin, &direction);
+            let bsdf = material.eval(ray, hit_rec, &direction);
+            if pdf <= 0.0 || bsdf.x + bsdf.y + bsdf.z <= 0.0 {
+                continue;
+            }
+
+            // Only the sampled light counts, anything in front of it (even another light) blocks the shadow ray
+            let shadow_ray = Ray::new(hit_rec.origin, direction);
+            let mut light_rec = HitRecord::new_empty();
+            if !light.hit(&shadow_ray, EPSILON, INFINITY, &mut light_rec) {
+                continue;
+            }
+
+            let mut blocker_rec = HitRecord::new_empty();
+            if !world.hit(&shadow_ray, EPSILON, light_rec.t * (1.0 - SHADOW_TOLERANCE), &mut blocker_rec) {
+                colour += bsdf * light_rec.material.unwrap().emit(&light_rec) / pdf;
+            }
+        }
+
+        colour
+    }
+}
+
+impl Integrator for Whitted {
+    /// # `colour`
+    /// Returns the colour of the ray lit directly by the lights, following mirror reflections and refractions
+    fn colour(&self, ray: &Ray, world: &World) -> Colour {
+        Whitted::trace(ray, world, self.max_bounce)
+    }
+}
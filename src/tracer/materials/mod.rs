@@ -1,5 +1,19 @@
+use std::sync::Arc;
 use super::*;
 use crate::utils::{Colour, Vector3, random_f32, ORIGIN};
+use std::f32::consts::PI;
+
+// Metals with less fuzziness than this are perfect mirrors
+const MIRROR_FUZZ: f32 = 1.0e-3;
+
+/// # `Scatter`
+/// The ray a material scatters into, how much of its light is kept as `Colour` (the BSDF times cosine divided by the pdf)
+/// and the probability density the direction was picked with (`None` for specular scattering)
+pub struct Scatter {
+    pub ray: Ray,
+    pub attenuation: Colour,
+    pub pdf: Option<f32>
+}
 
 /// # `MaterialType`
 /// Enum type to specify the Material type used and their extra values (fuzziness or index of refraction)
@@ -12,39 +26,108 @@ pub enum MaterialType {
 }
 
 /// # `Material`
-/// Struct that handles the colour and material type for an object
-#[derive(Copy, Clone)]
+/// Struct that handles the colour (as a `Texture`) and material type for an object.
+/// Metals can have a roughness texture which replaces their constant fuzziness
+#[derive(Clone)]
 pub struct Material {
-    pub colour: Colour,
+    pub texture: Arc<dyn Texture>,
+    pub roughness: Option<Arc<dyn Texture>>,
     pub material_type: MaterialType
 }
 
 impl Material {
     /// # `new`
-    /// Creates a new material by giving its `Colour` and `MaterialType`
-    pub fn new(colour: Colour, material_type: MaterialType) -> Material {
+    /// Creates a new material whose colour is given by the texture `Arc<dyn Texture>` and its `MaterialType`
+    pub fn new(texture: Arc<dyn Texture>, material_type: MaterialType) -> Material {
         Material {
-            colour: colour,
-            material_type: material_type
+            texture,
+            roughness: None,
+            material_type
+        }
+    }
+
+    /// # `with_roughness`
+    /// Returns the material with the fuzziness of a metal given by the texture `Arc<dyn Texture>` (the average of its colour channels)
+    pub fn with_roughness(mut self, roughness: Arc<dyn Texture>) -> Material {
+        self.roughness = Some(roughness);
+        self
+    }
+
+    /// # `colour`
+    /// Returns the colour of the material at the hit given by the `HitRecord`
+    pub fn colour(&self, hit_record: &HitRecord) -> Colour {
+        self.texture.value(hit_record.u, hit_record.v, &hit_record.origin)
+    }
+
+    /// # `sample`
+    /// Picks a random direction the given `Ray` scatters into after the hit given by the `HitRecord`. Returns `None` if the ray is absorbed
+    pub fn sample(&self, ray: &Ray, hit_record: &HitRecord) -> Option<Scatter> {
+        match self.material_type {
+            MaterialType::Lambertian => Some(self.lambertian(hit_record)),
+            MaterialType::Metal(fuzz) => self.metal(ray, hit_record, fuzz),
+            MaterialType::Dielectric(refrac_idx) => Some(self.dielectric(ray, hit_record, refrac_idx)),
+            MaterialType::DiffuseLight => None
         }
     }
 
-    /// # `scatter`
-    /// Returns if the ray hits the object and modifies given data to caluclate how the ray scatters afterwards (if it does)
-    pub fn scatter(&self, ray: &Ray, hit_record: &HitRecord, attenuation: &mut Colour, scattered: &mut Ray) -> bool {
+    /// # `eval`
+    /// Returns the BSDF times the cosine of the given direction `Vector3` with the normal, which is the share of light arriving from the direction that leaves along the given `Ray` reversed.
+    /// Specular materials can not be evaluated and return black
+    pub fn eval(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vector3) -> Colour {
+        let cosine = direction.unit().dot(hit_record.normal);
+        if cosine <= 0.0 || self.is_specular(hit_record) {
+            return ORIGIN;
+        }
+
         match self.material_type {
-            MaterialType::Lambertian => self.lambertian(ray, hit_record, attenuation, scattered),
-            MaterialType::Metal(fuzz) => self.metal(ray, hit_record, attenuation, fuzz, scattered),
-            MaterialType::Dielectric(refrac_idx) => self.dielectric(ray, hit_record, attenuation, refrac_idx, scattered),
-            MaterialType::DiffuseLight => {false}
+            MaterialType::Lambertian => self.colour(hit_record) * (cosine / PI),
+            MaterialType::Metal(fuzz) => {
+                let exponent = Material::phong_exponent(self.fuzz(hit_record, fuzz));
+                let reflected = ray.direction.unit().reflect(hit_record.normal);
+                let cos_alpha = direction.unit().dot(reflected).max(0.0);
+
+                self.colour(hit_record) * ((exponent + 2.0) / (2.0 * PI) * cos_alpha.powf(exponent) * cosine)
+            }
+            _ => ORIGIN
+        }
+    }
+
+    /// # `pdf`
+    /// Returns the probability density (over solid angle) that `sample` picks the given direction `Vector3`, specular materials return 0
+    pub fn pdf(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vector3) -> f32 {
+        let cosine = direction.unit().dot(hit_record.normal);
+        if cosine <= 0.0 || self.is_specular(hit_record) {
+            return 0.0;
+        }
+
+        match self.material_type {
+            MaterialType::Lambertian => cosine / PI,
+            MaterialType::Metal(fuzz) => {
+                let exponent = Material::phong_exponent(self.fuzz(hit_record, fuzz));
+                let reflected = ray.direction.unit().reflect(hit_record.normal);
+                let cos_alpha = direction.unit().dot(reflected).max(0.0);
+
+                (exponent + 1.0) / (2.0 * PI) * cos_alpha.powf(exponent)
+            }
+            _ => 0.0
+        }
+    }
+
+    /// # `is_specular`
+    /// Returns true if the material scatters into a single direction at the hit given by the `HitRecord` (or does not scatter at all), then `eval` and `pdf` are always 0 and lights can not be sampled
+    pub fn is_specular(&self, hit_record: &HitRecord) -> bool {
+        match self.material_type {
+            MaterialType::Lambertian => false,
+            MaterialType::Metal(fuzz) => self.fuzz(hit_record, fuzz) < MIRROR_FUZZ,
+            MaterialType::Dielectric(_) | MaterialType::DiffuseLight => true
         }
     }
 
     /// # `emit`
-    /// Returns the colour of emission of this material (only for DiffuseLight materials)
-    pub fn emit(&self) -> Colour {
+    /// Returns the colour of emission of this material at the hit given by the `HitRecord` (only for DiffuseLight materials)
+    pub fn emit(&self, hit_record: &HitRecord) -> Colour {
         match self.material_type {
-            MaterialType::DiffuseLight => self.colour,
+            MaterialType::DiffuseLight => self.colour(hit_record),
             _ => ORIGIN // Black
         }
     }
@@ -52,47 +135,93 @@ impl Material {
     
     // === CALCULATIONS OF HOW DIFFERENT MATERIALS HANDLES THE RAY ===
 
-    fn lambertian(&self, _ray: &Ray, hit_record: &HitRecord, attenuation: &mut Colour, scattered: &mut Ray) -> bool {
-        let mut scatter_direction = hit_record.normal + Vector3::random_in_hemisphere(&hit_record.normal).unit();
+    fn lambertian(&self, hit_record: &HitRecord) -> Scatter {
+        // Cosine weighted direction around the normal
+        let mut scatter_direction = hit_record.normal + Vector3::random_in_unit_sphere().unit();
 
         if scatter_direction.near_zero() {
             scatter_direction = hit_record.normal;
         }
 
-        *scattered = Ray::new(hit_record.origin, scatter_direction);
-        *attenuation = self.colour.clone();
-        true
+        Scatter {
+            ray: Ray::new(hit_record.origin, scatter_direction),
+            attenuation: self.colour(hit_record),
+            pdf: Some(scatter_direction.unit().dot(hit_record.normal).max(0.0) / PI)
+        }
     }
 
-    fn metal(&self, ray: &Ray, hit_record: &HitRecord, attenuation: &mut Colour, fuzz: f32, scattered: &mut Ray) -> bool {
+    fn metal(&self, ray: &Ray, hit_record: &HitRecord, fuzz: f32) -> Option<Scatter> {
+        let fuzz = self.fuzz(hit_record, fuzz);
         let reflected = ray.direction.unit().reflect(hit_record.normal);
-        *scattered = Ray::new(hit_record.origin, reflected + fuzz * Vector3::random_in_unit_sphere());
-        *attenuation = self.colour.clone();
 
-        scattered.direction.dot(hit_record.normal) > 0.0
+        if fuzz < MIRROR_FUZZ {
+            return Some(Scatter {
+                ray: Ray::new(hit_record.origin, reflected),
+                attenuation: self.colour(hit_record),
+                pdf: None
+            });
+        }
+
+        // Direction in the Phong lobe around the mirror reflection
+        let exponent = Material::phong_exponent(fuzz);
+        let cos_alpha = random_f32().powf(1.0 / (exponent + 1.0));
+        let sin_alpha = (1.0 - cos_alpha * cos_alpha).sqrt();
+        let phi = 2.0 * PI * random_f32();
+        let (u, v) = reflected.basis();
+        let direction = sin_alpha * phi.cos() * u + sin_alpha * phi.sin() * v + cos_alpha * reflected;
+
+        let cosine = direction.dot(hit_record.normal);
+        if cosine <= 0.0 {
+            return None; // Below the surface
+        }
+
+        // BSDF * cosine / pdf of the normalised Phong lobe
+        Some(Scatter {
+            ray: Ray::new(hit_record.origin, direction),
+            attenuation: self.colour(hit_record) * ((exponent + 2.0) / (exponent + 1.0) * cosine),
+            pdf: Some((exponent + 1.0) / (2.0 * PI) * cos_alpha.powf(exponent))
+        })
     }
 
-    fn dielectric(&self, ray: &Ray, hit_record: &HitRecord, attenuation: &mut Colour, refraction_index: f32, scattered: &mut Ray) -> bool {
-        *attenuation = Colour::new(1.0, 1.0, 1.0);
+    fn dielectric(&self, ray: &Ray, hit_record: &HitRecord, refraction_index: f32) -> Scatter {
         let refraction_index = if hit_record.front_face {1.0 / refraction_index} else {refraction_index};
 
         let unit_direction = ray.direction.unit();
 
         let cos_theta = (hit_record.normal.dot(-unit_direction)).min(1.0);
         let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
-        let direction;
 
-        if refraction_index * sin_theta > 1.0 
+        let direction = if refraction_index * sin_theta > 1.0 
         || Material::reflectance(cos_theta, refraction_index) > random_f32() {
-            direction = unit_direction.reflect(hit_record.normal);
+            unit_direction.reflect(hit_record.normal)
         }
         else {
-            direction = unit_direction.refract(hit_record.normal, refraction_index);
+            unit_direction.refract(hit_record.normal, refraction_index)
+        };
+
+        Scatter {
+            ray: Ray::new(hit_record.origin, direction),
+            attenuation: Colour::new(1.0, 1.0, 1.0),
+            pdf: None
         }
+    }
 
-        *scattered = Ray::new(hit_record.origin, direction);
+    /// # `fuzz`
+    /// Returns the fuzziness of a metal at the hit given by the `HitRecord`, read from the roughness texture if there is one instead of the given constant fuzz as `f32`
+    fn fuzz(&self, hit_record: &HitRecord, fuzz: f32) -> f32 {
+        match &self.roughness {
+            Some(_roughness) => {
+                let value = _roughness.value(hit_record.u, hit_record.v, &hit_record.origin);
+                (value.x + value.y + value.z) / 3.0
+            }
+            None => fuzz
+        }
+    }
 
-        true
+    /// # `phong_exponent`
+    /// Returns the exponent of the Phong lobe for the given fuzziness as `f32`, a fuzz of 1 scatters evenly over the hemisphere around the reflection
+    fn phong_exponent(fuzz: f32) -> f32 {
+        (2.0 / (fuzz * fuzz) - 2.0).max(0.0)
     }
 
     fn reflectance(cosine: f32, refrac_idx: f32) -> f32 {
@@ -2,15 +2,29 @@ mod ray;
 mod camera;
 mod objects;
 mod materials;
+mod textures;
+mod integrators;
 
 pub use self::{
     ray::Ray,
-    camera::Camera,
+    camera::{
+        Camera,
+        Progressive,
+        Checkpoint,
+        RenderSettings
+    },
     objects::{
-        World, Sphere, HitRecord, Rectangle, Axis
+        World, Sphere, HitRecord, Rectangle, Axis, Quad, Disk, Cuboid, Cylinder, Cone, Capsule, Plane, Torus, Triangle, Mesh, Transformed, Object
     },
     materials::{
         Material,
         MaterialType
+    },
+    textures::{
+        Texture, SolidColour, Checker, ImageTexture, NoiseTexture, NoisePattern
+    },
+    integrators::{
+        Integrator,
+        from_name as integrator_from_name
     }
 };
\ No newline at end of file
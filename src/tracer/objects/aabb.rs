@@ -0,0 +1,87 @@
+use super::Ray;
+use crate::utils::Vector3;
+
+/// # `Aabb`
+/// Axis-aligned bounding box given by its smallest and largest corner. Used to quickly discard rays that cannot hit the objects inside the box
+#[derive(Copy, Clone)]
+pub struct Aabb {
+    pub min: Vector3,
+    pub max: Vector3
+}
+
+impl Aabb {
+    /// # `new`
+    /// Creates a new bounding box using its smallest and largest corners as `Vector3`
+    pub fn new(min: Vector3, max: Vector3) -> Aabb {
+        Aabb {
+            min,
+            max
+        }
+    }
+
+    /// # `from_points`
+    /// Returns the smallest `Aabb` that contains all the given points. The slice must not be empty
+    pub fn from_points(points: &[Vector3]) -> Aabb {
+        points
+            .iter()
+            .skip(1)
+            .fold(Aabb::new(points[0], points[0]), |_acc, _point| Aabb::surrounding(&_acc, &Aabb::new(*_point, *_point)))
+    }
+
+    /// # `surrounding`
+    /// Returns the smallest `Aabb` that contains both given boxes
+    pub fn surrounding(a: &Aabb, b: &Aabb) -> Aabb {
+        Aabb {
+            min: Vector3::new(a.min.x.min(b.min.x), a.min.y.min(b.min.y), a.min.z.min(b.min.z)),
+            max: Vector3::new(a.max.x.max(b.max.x), a.max.y.max(b.max.y), a.max.z.max(b.max.z))
+        }
+    }
+
+    /// # `padded`
+    /// Returns the box grown by the given amount as `f32` on every side. Used for flat objects so the box never has zero thickness
+    pub fn padded(&self, amount: f32) -> Aabb {
+        let pad = Vector3::new(amount, amount, amount);
+        Aabb::new(self.min - pad, self.max + pad)
+    }
+
+    /// # `centroid`
+    /// Returns the center of the box as `Vector3`
+    pub fn centroid(&self) -> Vector3 {
+        0.5 * (self.min + self.max)
+    }
+
+    /// # `surface_area`
+    /// Returns the surface area of the box as `f32`
+    pub fn surface_area(&self) -> f32 {
+        let d = self.max - self.min;
+        2.0 * (d.x * d.y + d.y * d.z + d.z * d.x)
+    }
+
+    /// # `largest_axis`
+    /// Returns the index (0 = x, 1 = y, 2 = z) of the axis in which the box is the longest
+    pub fn largest_axis(&self) -> usize {
+        let d = self.max - self.min;
+        if d.x > d.y && d.x > d.z {0} else if d.y > d.z {1} else {2}
+    }
+
+    /// # `hit`
+    /// Returns true if the given `Ray` passes through the box within the parameter boundries t_min, t_max as `f32` (slab test)
+    pub fn hit(&self, ray: &Ray, mut t_min: f32, mut t_max: f32) -> bool {
+        for axis in 0..3 {
+            let inv_d = 1.0 / ray.direction[axis];
+            let mut t0 = (self.min[axis] - ray.origin[axis]) * inv_d;
+            let mut t1 = (self.max[axis] - ray.origin[axis]) * inv_d;
+            if inv_d < 0.0 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+
+            t_min = if t0 > t_min {t0} else {t_min};
+            t_max = if t1 < t_max {t1} else {t_max};
+            if t_max <= t_min {
+                return false;
+            }
+        }
+
+        true
+    }
+}
@@ -0,0 +1,100 @@
+use super::{Ray, HitRecord, Material, Aabb};
+use crate::utils::{Vector3, EPSILON};
+use std::f32::consts::PI;
+
+/// # `AxisFrame`
+/// Local coordinate system of an object built around the axis between two points. The axis starts at `start`, runs along `w` for `height`
+/// and `u`, `v` are perpendicular to it. The objects around an axis are intersected in this frame where the axis is the z axis
+pub(super) struct AxisFrame {
+    pub start: Vector3,
+    pub u: Vector3,
+    pub v: Vector3,
+    pub w: Vector3,
+    pub height: f32
+}
+
+impl AxisFrame {
+    /// # `new`
+    /// Creates the frame of the axis from the given start to the given end as `Vector3`. The points must be different
+    pub fn new(start: Vector3, end: Vector3) -> AxisFrame {
+        let axis = end - start;
+        let w = axis.unit();
+        let (u, v) = w.basis();
+
+        AxisFrame {
+            start,
+            u,
+            v,
+            w,
+            height: axis.norm()
+        }
+    }
+
+    /// # `local_ray`
+    /// Returns the origin and direction of the given `Ray` in the frame as `(Vector3, Vector3)`. The frame is not scaled, so the ray parameter t stays the same
+    pub fn local_ray(&self, ray: &Ray) -> (Vector3, Vector3) {
+        (self.to_local(&(ray.origin - self.start)), self.to_local(&ray.direction))
+    }
+
+    /// # `to_local`
+    /// Returns the given direction `Vector3` in the frame
+    pub fn to_local(&self, vector: &Vector3) -> Vector3 {
+        Vector3::new(vector.dot(self.u), vector.dot(self.v), vector.dot(self.w))
+    }
+
+    /// # `to_world`
+    /// Returns the given direction `Vector3` of the frame in world space
+    pub fn to_world(&self, vector: &Vector3) -> Vector3 {
+        vector.x * self.u + vector.y * self.v + vector.z * self.w
+    }
+
+    /// # `disk_bounds`
+    /// Returns the box surrounding a disk of the given radius as `f32` around the given point `Vector3` of the axis, perpendicular to the axis
+    pub fn disk_bounds(&self, center: Vector3, radius: f32) -> Aabb {
+        let reach = |_w: f32| radius * (1.0 - _w * _w).max(0.0).sqrt();
+        let extent = Vector3::new(reach(self.w.x), reach(self.w.y), reach(self.w.z));
+
+        Aabb::new(center - extent, center + extent)
+    }
+}
+
+/// # `quadratic_roots`
+/// Returns the real roots of a t² + 2 b_half t + c = 0 as `(f32, f32)` with the smaller root first, or `None` if there are none.
+/// If a is 0 the single root of the linear equation is returned twice
+pub(super) fn quadratic_roots(a: f32, b_half: f32, c: f32) -> Option<(f32, f32)> {
+    if a.abs() < EPSILON {
+        if b_half.abs() < EPSILON {
+            return None;
+        }
+        let t = -c / (2.0 * b_half);
+        return Some((t, t));
+    }
+
+    let discriminant = b_half * b_half - a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let sqrt_disc = discriminant.sqrt();
+    let (t0, t1) = ((-b_half - sqrt_disc) / a, (-b_half + sqrt_disc) / a);
+    Some((t0.min(t1), t0.max(t1)))
+}
+
+/// # `angle_u`
+/// Returns the texture coordinate u in [0, 1] going once around the axis for the given local x and y as `f32`
+pub(super) fn angle_u(x: f32, y: f32) -> f32 {
+    (y.atan2(x) + PI) / (2.0 * PI)
+}
+
+/// # `fill_record`
+/// Writes the hit at the given `(t, local normal, u, v)` into the `HitRecord`. The outward normal is given in the `AxisFrame` and turned into world space
+pub(super) fn fill_record<'a>(ray: &Ray, frame: &AxisFrame, hit: (f32, Vector3, f32, f32), material: &'a Material, hit_record: &mut HitRecord<'a>) {
+    let (t, normal, u, v) = hit;
+
+    hit_record.t = t;
+    hit_record.origin = ray.at(t);
+    hit_record.face_normal(ray, &frame.to_world(&normal));
+    hit_record.u = u;
+    hit_record.v = v;
+    hit_record.material = Some(material);
+}
@@ -0,0 +1,238 @@
+use super::{Object, HitRecord, Ray, Aabb};
+
+// Amount of objects in a leaf before it is split further
+const MAX_LEAF_SIZE: usize = 4;
+
+// Amount of buckets the objects are sorted into when looking for the cheapest split
+const BUCKETS: usize = 12;
+
+/// # `BvhContent`
+/// What a `BvhNode` holds, either objects (a leaf) or two child nodes
+enum BvhContent {
+    Leaf(Vec<Box<dyn Object>>),
+    Branch {
+        axis: usize,
+        left: Box<BvhNode>,
+        right: Box<BvhNode>
+    }
+}
+
+/// # `BvhNode`
+/// A node of a bounding volume hierarchy. Objects are grouped into nested bounding boxes so a ray only has to test the objects whose boxes it passes through
+pub struct BvhNode {
+    bbox: Aabb,
+    content: BvhContent
+}
+
+impl BvhNode {
+    /// # `new`
+    /// Builds a bounding volume hierarchy from the given objects. The splits are chosen using the surface area heuristic.
+    /// All the objects must have a bounding box
+    pub fn new(objects: Vec<Box<dyn Object>>) -> BvhNode {
+        let items = objects
+            .into_iter()
+            .map(|_object| (_object.bounding_box().expect("Object without a bounding box given to BVH"), _object))
+            .collect();
+
+        Self::build(items)
+    }
+
+    fn build(mut items: Vec<(Aabb, Box<dyn Object>)>) -> BvhNode {
+        let bbox = items
+            .iter()
+            .skip(1)
+            .fold(items[0].0, |_acc, _item| Aabb::surrounding(&_acc, &_item.0));
+
+        if items.len() <= MAX_LEAF_SIZE {
+            return Self::leaf(bbox, items);
+        }
+
+        // Split along the longest axis of the box around the centroids
+        let centroid_box = items
+            .iter()
+            .skip(1)
+            .fold(Aabb::new(items[0].0.centroid(), items[0].0.centroid()), |_acc, _item| {
+                Aabb::surrounding(&_acc, &Aabb::new(_item.0.centroid(), _item.0.centroid()))
+            });
+        let axis = centroid_box.largest_axis();
+        let low = centroid_box.min[axis];
+        let extent = centroid_box.max[axis] - low;
+
+        let bucket_of = |_bbox: &Aabb| -> usize {
+            if extent <= 0.0 {
+                return 0;
+            }
+            (((_bbox.centroid()[axis] - low) / extent * BUCKETS as f32) as usize).min(BUCKETS - 1)
+        };
+
+        // Sort the objects into buckets and find the split between buckets with the lowest cost
+        let mut counts = [0usize; BUCKETS];
+        let mut bounds: [Option<Aabb>; BUCKETS] = [None; BUCKETS];
+        for (item_box, _) in items.iter() {
+            let b = bucket_of(item_box);
+            counts[b] += 1;
+            bounds[b] = Some(match bounds[b] {
+                Some(_bound) => Aabb::surrounding(&_bound, item_box),
+                None => *item_box
+            });
+        }
+
+        let mut best_split = 0;
+        let mut best_cost = f32::INFINITY;
+        for split in 1..BUCKETS {
+            let cost = Self::side_cost(&counts[..split], &bounds[..split])
+                + Self::side_cost(&counts[split..], &bounds[split..]);
+            if cost < best_cost {
+                best_cost = cost;
+                best_split = split;
+            }
+        }
+
+        let (mut left, mut right): (Vec<_>, Vec<_>) = items
+            .drain(..)
+            .partition(|_item| bucket_of(&_item.0) < best_split);
+
+        // All centroids in the same bucket, fall back to splitting in the middle of the sorted objects
+        if left.is_empty() || right.is_empty() {
+            left.append(&mut right);
+            left.sort_by(|a, b| a.0.centroid()[axis].total_cmp(&b.0.centroid()[axis]));
+            right = left.split_off(left.len() / 2);
+        }
+
+        BvhNode {
+            bbox,
+            content: BvhContent::Branch {
+                axis,
+                left: Box::new(Self::build(left)),
+                right: Box::new(Self::build(right))
+            }
+        }
+    }
+
+    fn leaf(bbox: Aabb, items: Vec<(Aabb, Box<dyn Object>)>) -> BvhNode {
+        BvhNode {
+            bbox,
+            content: BvhContent::Leaf(items.into_iter().map(|_item| _item.1).collect())
+        }
+    }
+
+    // Surface area heuristic cost of one side of a split
+    fn side_cost(counts: &[usize], bounds: &[Option<Aabb>]) -> f32 {
+        let count: usize = counts.iter().sum();
+        let area = bounds
+            .iter()
+            .flatten()
+            .fold(None, |_acc: Option<Aabb>, _bound| Some(match _acc {
+                Some(_acc) => Aabb::surrounding(&_acc, _bound),
+                None => *_bound
+            }))
+            .map_or(0.0, |_bound| _bound.surface_area());
+
+        count as f32 * area
+    }
+}
+
+impl Object for BvhNode {
+
+    /// # `hit`
+    /// Returns true if the ray `Ray` hit any object in the hierarchy within the given parameter boundries t_min, t_max as `f32` and the hit_record of the ray `HitRecord`
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
+        if !self.bbox.hit(ray, t_min, t_max) {
+            return false;
+        }
+
+        match &self.content {
+            BvhContent::Leaf(objects) => {
+                let mut temp: HitRecord = HitRecord::new_empty();
+                let mut hit_anything = false;
+                let mut closest = t_max;
+
+                for object in objects.iter() {
+                    if object.hit(ray, t_min, closest, &mut temp) {
+                        hit_anything = true;
+                        closest = temp.t;
+                        *hit_record = temp;
+                    }
+                }
+
+                hit_anything
+            }
+            BvhContent::Branch {axis, left, right} => {
+                // Visit the child closer to the ray first so the further one can be cut off sooner
+                let (first, second) = if ray.direction[*axis] < 0.0 {(right, left)} else {(left, right)};
+
+                let hit_first = first.hit(ray, t_min, t_max, hit_record);
+                let closest = if hit_first {hit_record.t} else {t_max};
+                let hit_second = second.hit(ray, t_min, closest, hit_record);
+
+                hit_first || hit_second
+            }
+        }
+    }
+
+    /// # `bounding_box`
+    /// Returns the box surrounding the whole hierarchy
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(self.bbox)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracer::{Material, MaterialType, SolidColour, Sphere, Triangle, World};
+    use crate::utils::{Colour, Vector3, random_range, seed_random};
+
+    /// Random spheres and triangles in the cube [-10, 10]³, the same every call
+    fn scene() -> Vec<Box<dyn Object>> {
+        seed_random(1, 0);
+        let material = Material::new(SolidColour::new(Colour::new(0.5, 0.5, 0.5)), MaterialType::Lambertian);
+
+        (0..200).map(|_idx| {
+            let center = Vector3::random_bounded(-10.0, 10.0);
+            if _idx % 2 == 0 {
+                Sphere::new(center, random_range(0.1, 1.0), material.clone())
+            } else {
+                let corner = || center + Vector3::random_bounded(-1.0, 1.0);
+                Triangle::new([corner(), corner(), corner()], None, material.clone())
+            }
+        }).collect()
+    }
+
+    #[test]
+    fn hits_the_same_as_a_linear_scan() {
+        let bvh = BvhNode::new(scene());
+        let mut linear = World::new_empty();
+        linear.objects = scene();
+
+        seed_random(2, 0);
+        let mut hits = 0;
+        for _ in 0..2000 {
+            let ray = Ray::new(Vector3::random_bounded(-15.0, 15.0), Vector3::random_bounded(-1.0, 1.0));
+            let mut bvh_hit = HitRecord::new_empty();
+            let mut linear_hit = HitRecord::new_empty();
+
+            let hit = bvh.hit(&ray, 1.0e-3, f32::INFINITY, &mut bvh_hit);
+            assert_eq!(hit, linear.hit(&ray, 1.0e-3, f32::INFINITY, &mut linear_hit));
+            if hit {
+                hits += 1;
+                assert_eq!(bvh_hit.t, linear_hit.t);
+                assert_eq!(bvh_hit.origin.x, linear_hit.origin.x);
+            }
+        }
+        assert!(hits > 100, "only {} of the rays hit something", hits);
+    }
+
+    #[test]
+    fn bounding_box_contains_every_object() {
+        let objects = scene();
+        let boxes: Vec<Aabb> = objects.iter().map(|_object| _object.bounding_box().unwrap()).collect();
+        let bbox = BvhNode::new(objects).bounding_box().unwrap();
+
+        for _box in boxes {
+            for axis in 0..3 {
+                assert!(bbox.min[axis] <= _box.min[axis] && _box.max[axis] <= bbox.max[axis]);
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use super::{Object, Material, Ray, HitRecord, Aabb};
+use super::axial::{AxisFrame, quadratic_roots, angle_u, fill_record};
+use crate::utils::Vector3;
+
+/// # `Capsule`
+/// A cylinder around the axis between two points closed by a half sphere at each end, so every point of the surface is the radius away from the axis
+pub struct Capsule {
+    pub start: Vector3,
+    pub end: Vector3,
+    pub radius: f32,
+    pub material: Material,
+    frame: AxisFrame
+}
+
+impl Capsule {
+    /// # `new`
+    /// Creates a new capsule around the axis from the given start to the given end as `Vector3` with the radius as `f32` and its `Material`
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(start: Vector3, end: Vector3, radius: f32, material: Material) -> Box<dyn Object> {
+        Box::new(Capsule {
+            start,
+            end,
+            radius,
+            material,
+            frame: AxisFrame::new(start, end)
+        })
+    }
+}
+
+impl Object for Capsule {
+
+    /// # `hit`
+    /// Returns true if the ray `Ray` hit the capsule within the given parameter boundries t_min, t_max as `f32` and the hit_record of the ray `HitRecord`.
+    /// u goes around the axis and v along the whole capsule from the tip of the first half sphere to the tip of the second
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
+        let (o, d) = self.frame.local_ray(ray);
+        let (r, h) = (self.radius, self.frame.height);
+        let v_of = |_z: f32| (_z + r) / (h + 2.0 * r);
+        let mut closest = t_max;
+        let mut found = None;
+
+        // Side, x² + y² = r² between the two ends
+        if let Some((t0, t1)) = quadratic_roots(d.x * d.x + d.y * d.y, o.x * d.x + o.y * d.y, o.x * o.x + o.y * o.y - r * r) {
+            for t in [t0, t1] {
+                let z = o.z + t * d.z;
+                if t < t_min || t > closest || !(0.0..=h).contains(&z) {
+                    continue;
+                }
+
+                let (x, y) = (o.x + t * d.x, o.y + t * d.y);
+                closest = t;
+                found = Some((t, Vector3::new(x, y, 0.0) / r, angle_u(x, y), v_of(z)));
+                break;
+            }
+        }
+
+        // Half spheres, only the half beyond each end of the axis is part of the capsule
+        for center_z in [0.0, h] {
+            let oc = o - Vector3::new(0.0, 0.0, center_z);
+            let Some((t0, t1)) = quadratic_roots(d.norm_squared(), oc.dot(d), oc.norm_squared() - r * r) else {
+                continue;
+            };
+
+            for t in [t0, t1] {
+                let point = oc + t * d;
+                let beyond = if center_z == 0.0 {point.z <= 0.0} else {point.z >= 0.0};
+                if t < t_min || t > closest || !beyond {
+                    continue;
+                }
+
+                closest = t;
+                found = Some((t, point / r, angle_u(point.x, point.y), v_of(center_z + point.z)));
+                break;
+            }
+        }
+
+        match found {
+            Some(_hit) => {
+                fill_record(ray, &self.frame, _hit, &self.material, hit_record);
+                true
+            }
+            None => false
+        }
+    }
+
+    /// # `bounding_box`
+    /// Returns the box surrounding the spheres at both ends of the capsule
+    fn bounding_box(&self) -> Option<Aabb> {
+        let r = Vector3::new(self.radius, self.radius, self.radius);
+        Some(Aabb::surrounding(&Aabb::new(self.start - r, self.start + r), &Aabb::new(self.end - r, self.end + r)))
+    }
+}
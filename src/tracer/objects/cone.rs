@@ -0,0 +1,86 @@
+use super::{Object, Material, Ray, HitRecord, Aabb};
+use super::axial::{AxisFrame, quadratic_roots, angle_u, fill_record};
+use crate::utils::{Vector3, EPSILON};
+
+/// # `Cone`
+/// A cone with a round base around one point narrowing to its tip at another point. Without the cap the base is open
+pub struct Cone {
+    pub base: Vector3,
+    pub tip: Vector3,
+    pub radius: f32,
+    pub capped: bool,
+    pub material: Material,
+    frame: AxisFrame
+}
+
+impl Cone {
+    /// # `new`
+    /// Creates a new cone using the center of its base and its tip as `Vector3` and the radius of the base as `f32`.
+    /// `capped` closes the base with a disk. Takes the cone's `Material` as well
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(base: Vector3, tip: Vector3, radius: f32, capped: bool, material: Material) -> Box<dyn Object> {
+        Box::new(Cone {
+            base,
+            tip,
+            radius,
+            capped,
+            material,
+            frame: AxisFrame::new(base, tip)
+        })
+    }
+}
+
+impl Object for Cone {
+
+    /// # `hit`
+    /// Returns true if the ray `Ray` hit the cone within the given parameter boundries t_min, t_max as `f32` and the hit_record of the ray `HitRecord`.
+    /// On the side u goes around the axis and v from the base to the tip, on the cap the square around it is mapped onto (0, 1)
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
+        let (o, d) = self.frame.local_ray(ray);
+        let (r, h) = (self.radius, self.frame.height);
+        let slope_sq = (r / h) * (r / h);
+        let mut closest = t_max;
+        let mut found = None;
+
+        // Side, x² + y² = (r / h)² (h - z)² between the base and the tip. The other half of the double cone (above the tip) is skipped
+        let q = h - o.z;
+        let a = d.x * d.x + d.y * d.y - slope_sq * d.z * d.z;
+        let b_half = o.x * d.x + o.y * d.y + slope_sq * q * d.z;
+        let c = o.x * o.x + o.y * o.y - slope_sq * q * q;
+        if let Some((t0, t1)) = quadratic_roots(a, b_half, c) {
+            for t in [t0, t1] {
+                let z = o.z + t * d.z;
+                if t < t_min || t > closest || !(0.0..=h).contains(&z) {
+                    continue;
+                }
+
+                let (x, y) = (o.x + t * d.x, o.y + t * d.y);
+                closest = t;
+                found = Some((t, Vector3::new(x, y, slope_sq * (h - z)).unit(), angle_u(x, y), z / h));
+                break;
+            }
+        }
+
+        if self.capped && d.z.abs() > EPSILON {
+            let t = -o.z / d.z;
+            let (x, y) = (o.x + t * d.x, o.y + t * d.y);
+            if t >= t_min && t <= closest && x * x + y * y <= r * r {
+                found = Some((t, Vector3::new(0.0, 0.0, -1.0), 0.5 + x / (2.0 * r), 0.5 + y / (2.0 * r)));
+            }
+        }
+
+        match found {
+            Some(_hit) => {
+                fill_record(ray, &self.frame, _hit, &self.material, hit_record);
+                true
+            }
+            None => false
+        }
+    }
+
+    /// # `bounding_box`
+    /// Returns the box surrounding the base and the tip of the cone
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(Aabb::surrounding(&self.frame.disk_bounds(self.base, self.radius), &Aabb::new(self.tip, self.tip)))
+    }
+}
@@ -0,0 +1,76 @@
+use super::{Object, Material, Ray, HitRecord, Aabb, Quad};
+use crate::utils::{Vector3, random_f32};
+
+/// # `Cuboid`
+/// An axis-aligned box made of six `Quad` faces whose fronts all point outwards, so the box is closed and can hold dielectrics.
+/// Rotated boxes are made by placing it with the transform modifiers
+pub struct Cuboid {
+    pub min: Vector3,
+    pub max: Vector3,
+    faces: Vec<Box<dyn Object>>
+}
+
+impl Cuboid {
+    /// # `new`
+    /// Creates a new box using its smallest and largest corner as `Vector3` and the `Material` of all of its faces
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(min: Vector3, max: Vector3, material: Material) -> Box<dyn Object> {
+        let dx = Vector3::new(max.x - min.x, 0.0, 0.0);
+        let dy = Vector3::new(0.0, max.y - min.y, 0.0);
+        let dz = Vector3::new(0.0, 0.0, max.z - min.z);
+
+        let faces = vec![
+            Quad::new(Vector3::new(min.x, min.y, max.z), dx, dy, material.clone()), // front
+            Quad::new(Vector3::new(max.x, min.y, max.z), -dz, dy, material.clone()), // right
+            Quad::new(Vector3::new(max.x, min.y, min.z), -dx, dy, material.clone()), // back
+            Quad::new(min, dz, dy, material.clone()), // left
+            Quad::new(Vector3::new(min.x, max.y, max.z), dx, -dz, material.clone()), // top
+            Quad::new(min, dx, dz, material) // bottom
+        ];
+
+        Box::new(Cuboid {
+            min,
+            max,
+            faces
+        })
+    }
+}
+
+impl Object for Cuboid {
+
+    /// # `hit`
+    /// Returns true if the ray `Ray` hit any face of the box within the given parameter boundries t_min, t_max as `f32` and the hit_record of the ray `HitRecord`
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
+        let mut hit_anything = false;
+        let mut closest = t_max;
+
+        for face in self.faces.iter() {
+            if face.hit(ray, t_min, closest, hit_record) {
+                hit_anything = true;
+                closest = hit_record.t;
+            }
+        }
+
+        hit_anything
+    }
+
+    /// # `bounding_box`
+    /// Returns the box itself
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(Aabb::new(self.min, self.max))
+    }
+
+    /// # `pdf_value`
+    /// Returns the probability density of picking the given direction from the given origin, a face is picked uniformly and then a point on it
+    fn pdf_value(&self, origin: &Vector3, direction: &Vector3) -> f32 {
+        let sum: f32 = self.faces.iter().map(|_face| _face.pdf_value(origin, direction)).sum();
+        sum / self.faces.len() as f32
+    }
+
+    /// # `random`
+    /// Returns the direction from the given origin to a random point on a random face of the box
+    fn random(&self, origin: &Vector3) -> Vector3 {
+        let idx = ((random_f32() * self.faces.len() as f32) as usize).min(self.faces.len() - 1);
+        self.faces[idx].random(origin)
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Object, Material, Ray, HitRecord, Aabb};
+use super::axial::{AxisFrame, quadratic_roots, angle_u, fill_record};
+use crate::utils::{Vector3, EPSILON};
+
+/// # `Cylinder`
+/// A cylinder around the axis between two points with a radius. Without caps it is an open tube
+pub struct Cylinder {
+    pub start: Vector3,
+    pub end: Vector3,
+    pub radius: f32,
+    pub capped: bool,
+    pub material: Material,
+    frame: AxisFrame
+}
+
+impl Cylinder {
+    /// # `new`
+    /// Creates a new cylinder around the axis from the given start to the given end as `Vector3` with the radius as `f32`.
+    /// `capped` closes both ends with disks. Takes the cylinder's `Material` as well
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(start: Vector3, end: Vector3, radius: f32, capped: bool, material: Material) -> Box<dyn Object> {
+        Box::new(Cylinder {
+            start,
+            end,
+            radius,
+            capped,
+            material,
+            frame: AxisFrame::new(start, end)
+        })
+    }
+}
+
+impl Object for Cylinder {
+
+    /// # `hit`
+    /// Returns true if the ray `Ray` hit the cylinder within the given parameter boundries t_min, t_max as `f32` and the hit_record of the ray `HitRecord`.
+    /// On the side u goes around the axis and v along it, on the caps the square around the cap is mapped onto (0, 1)
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
+        let (o, d) = self.frame.local_ray(ray);
+        let (r, h) = (self.radius, self.frame.height);
+        let mut closest = t_max;
+        let mut found = None;
+
+        // Side, x² + y² = r² between the two ends
+        if let Some((t0, t1)) = quadratic_roots(d.x * d.x + d.y * d.y, o.x * d.x + o.y * d.y, o.x * o.x + o.y * o.y - r * r) {
+            for t in [t0, t1] {
+                let z = o.z + t * d.z;
+                if t < t_min || t > closest || !(0.0..=h).contains(&z) {
+                    continue;
+                }
+
+                let (x, y) = (o.x + t * d.x, o.y + t * d.y);
+                closest = t;
+                found = Some((t, Vector3::new(x, y, 0.0) / r, angle_u(x, y), z / h));
+                break;
+            }
+        }
+
+        if self.capped && d.z.abs() > EPSILON {
+            for (z, side) in [(0.0, -1.0), (h, 1.0)] {
+                let t = (z - o.z) / d.z;
+                if t < t_min || t > closest {
+                    continue;
+                }
+
+                let (x, y) = (o.x + t * d.x, o.y + t * d.y);
+                if x * x + y * y > r * r {
+                    continue;
+                }
+
+                closest = t;
+                found = Some((t, Vector3::new(0.0, 0.0, side), 0.5 + x / (2.0 * r), 0.5 + y / (2.0 * r)));
+            }
+        }
+
+        match found {
+            Some(_hit) => {
+                fill_record(ray, &self.frame, _hit, &self.material, hit_record);
+                true
+            }
+            None => false
+        }
+    }
+
+    /// # `bounding_box`
+    /// Returns the box surrounding the disks at both ends of the cylinder
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(Aabb::surrounding(&self.frame.disk_bounds(self.start, self.radius), &self.frame.disk_bounds(self.end, self.radius)))
+    }
+}
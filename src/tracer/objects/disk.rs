@@ -0,0 +1,102 @@
+use super::{Object, Material, Ray, HitRecord, Aabb};
+use crate::utils::{Vector3, random_f32, EPSILON, INFINITY};
+use std::f32::consts::PI;
+
+/// # `Disk`
+/// A flat circle given by its center, the normal of its front side and its radius
+pub struct Disk {
+    pub center: Vector3,
+    pub normal: Vector3,
+    pub radius: f32,
+    pub material: Material,
+    axis_u: Vector3, // Directions on the disk used for the texture coordinates and sampling
+    axis_v: Vector3
+}
+
+impl Disk {
+    /// # `new`
+    /// Creates a new disk using the given center and normal as `Vector3`, the radius as `f32` and its `Material`
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(center: Vector3, normal: Vector3, radius: f32, material: Material) -> Box<dyn Object> {
+        let normal = normal.unit();
+        let (axis_u, axis_v) = normal.basis();
+
+        Box::new(Disk {
+            center,
+            normal,
+            radius,
+            material,
+            axis_u,
+            axis_v
+        })
+    }
+}
+
+impl Object for Disk {
+
+    /// # `hit`
+    /// Returns true if the ray `Ray` hit the disk within the given parameter boundries t_min, t_max as `f32` and the hit_record of the ray `HitRecord`.
+    /// The texture coordinates map the square around the disk onto (0, 1), like a label stuck onto it
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
+        let denominator = self.normal.dot(ray.direction);
+        if denominator.abs() < EPSILON {
+            return false; // Ray is parallel to the disk
+        }
+
+        let t = self.normal.dot(self.center - ray.origin) / denominator;
+        if t < t_min || t > t_max {
+            return false;
+        }
+
+        let point = ray.at(t);
+        let planar = point - self.center;
+        if planar.norm_squared() > self.radius * self.radius {
+            return false;
+        }
+
+        hit_record.t = t;
+        hit_record.u = 0.5 + planar.dot(self.axis_u) / (2.0 * self.radius);
+        hit_record.v = 0.5 + planar.dot(self.axis_v) / (2.0 * self.radius);
+        hit_record.face_normal(ray, &self.normal);
+        hit_record.material = Some(&self.material);
+        hit_record.origin = point;
+
+        true
+    }
+
+    /// # `bounding_box`
+    /// Returns the box surrounding the disk. The disk reaches less far along the axes its normal leans towards, padded so it is not infinitely thin
+    fn bounding_box(&self) -> Option<Aabb> {
+        let reach = |_n: f32| self.radius * (1.0 - _n * _n).max(0.0).sqrt();
+        let extent = Vector3::new(reach(self.normal.x), reach(self.normal.y), reach(self.normal.z));
+
+        Some(Aabb::new(self.center - extent, self.center + extent).padded(1.0e-4))
+    }
+
+    /// # `pdf_value`
+    /// Returns the probability density of picking the given direction from the given origin, points are picked uniformly over the area of the disk
+    fn pdf_value(&self, origin: &Vector3, direction: &Vector3) -> f32 {
+        let mut hit_record = HitRecord::new_empty();
+        if !self.hit(&Ray::new(*origin, *direction), EPSILON, INFINITY, &mut hit_record) {
+            return 0.0;
+        }
+
+        let area = PI * self.radius * self.radius;
+        let distance_squared = hit_record.t * hit_record.t * direction.norm_squared();
+        let cosine = (direction.dot(self.normal) / direction.norm()).abs();
+        if cosine < EPSILON {
+            return 0.0;
+        }
+
+        distance_squared / (cosine * area)
+    }
+
+    /// # `random`
+    /// Returns the direction from the given origin to a random point on the disk
+    fn random(&self, origin: &Vector3) -> Vector3 {
+        let r = self.radius * random_f32().sqrt();
+        let phi = 2.0 * PI * random_f32();
+
+        self.center + r * phi.cos() * self.axis_u + r * phi.sin() * self.axis_v - *origin
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use crate::utils::Vector3;
+use super::{Object, Ray, HitRecord, Material, Aabb, BvhNode, triangle};
+
+/// # `MeshData`
+/// The vertex and index buffers of a mesh, shared by all of its triangles.
+/// `normals` is either empty or holds one normal per vertex
+pub struct MeshData {
+    pub vertices: Vec<Vector3>,
+    pub normals: Vec<Vector3>,
+    pub indices: Vec<[usize; 3]>,
+    pub material: Material
+}
+
+/// # `Mesh`
+/// A triangle mesh. The triangles only store their index into the shared `MeshData` and are grouped into their own bounding volume hierarchy
+pub struct Mesh {
+    bvh: BvhNode
+}
+
+impl Mesh {
+    /// # `new`
+    /// Creates a new mesh using the given vertices as `Vec<Vector3>`, per-vertex normals as `Vec<Vector3>` (empty for flat shading),
+    /// the triangles as indices into the vertices `Vec<[usize; 3]>` and the `Material` of the mesh
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(vertices: Vec<Vector3>, normals: Vec<Vector3>, indices: Vec<[usize; 3]>, material: Material) -> Box<dyn Object> {
+        let data = Arc::new(MeshData {
+            vertices,
+            normals,
+            indices,
+            material
+        });
+
+        let triangles: Vec<Box<dyn Object>> = (0..data.indices.len())
+            .map(|_face| Box::new(MeshTriangle {data: Arc::clone(&data), face: _face}) as Box<dyn Object>)
+            .collect();
+
+        Box::new(Mesh {
+            bvh: BvhNode::new(triangles)
+        })
+    }
+}
+
+impl Object for Mesh {
+
+    /// # `hit`
+    /// Returns true if the ray `Ray` hit any triangle of the mesh within the given parameter boundries t_min, t_max as `f32` and the hit_record of the ray `HitRecord`
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
+        self.bvh.hit(ray, t_min, t_max, hit_record)
+    }
+
+    /// # `bounding_box`
+    /// Returns the box surrounding all triangles of the mesh
+    fn bounding_box(&self) -> Option<Aabb> {
+        self.bvh.bounding_box()
+    }
+}
+
+/// # `MeshTriangle`
+/// A single triangle of a `Mesh`, given by its face index into the shared `MeshData`
+struct MeshTriangle {
+    data: Arc<MeshData>,
+    face: usize
+}
+
+impl MeshTriangle {
+    fn vertices(&self) -> [Vector3; 3] {
+        let [a, b, c] = self.data.indices[self.face];
+        [self.data.vertices[a], self.data.vertices[b], self.data.vertices[c]]
+    }
+
+    fn normals(&self) -> Option<[Vector3; 3]> {
+        if self.data.normals.is_empty() {
+            return None;
+        }
+
+        let [a, b, c] = self.data.indices[self.face];
+        Some([self.data.normals[a], self.data.normals[b], self.data.normals[c]])
+    }
+}
+
+impl Object for MeshTriangle {
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
+        let vertices = self.vertices();
+
+        match triangle::intersect(ray, &vertices, t_min, t_max) {
+            Some(hit) => {
+                triangle::fill_record(ray, &vertices, self.normals().as_ref(), hit, &self.data.material, hit_record);
+                true
+            }
+            None => false
+        }
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(triangle::bounds(&self.vertices()))
+    }
+}
@@ -1,27 +1,32 @@
 use super::{Ray, Material};
-use crate::utils::{Vector3, ORIGIN};
+use crate::utils::{Vector3, ORIGIN, X_UNIT};
 
 #[derive(Copy, Clone)]
 /// # `HitRecord`
-/// Structure that holds the informatiom about hit object, its normal and orientation
-pub struct HitRecord {
+/// Structure that holds the informatiom about hit object, its normal, orientation and the texture coordinates (u, v) of the hit.
+/// The material is borrowed from the hit object
+pub struct HitRecord<'a> {
     pub origin: Vector3,
     pub normal: Vector3,
-    pub material: Option<Material>,
+    pub material: Option<&'a Material>,
     pub t: f32,
+    pub u: f32,
+    pub v: f32,
     pub front_face: bool
 }
 
-impl HitRecord {
+impl<'a> HitRecord<'a> {
     /// # `new_empty`
     /// Creates a new empty HitRecord
-    pub fn new_empty() -> HitRecord {
+    pub fn new_empty() -> HitRecord<'a> {
         let empty_vec = ORIGIN;
         HitRecord {
             origin: empty_vec,
             normal: empty_vec,
             material: None,
             t: 0.0,
+            u: 0.0,
+            v: 0.0,
             front_face: false
         }
     }
@@ -35,22 +40,66 @@ impl HitRecord {
 }
 
 /// # `Object`
-/// Trait used for objects that are hittable by a ray
-pub trait Object {
+/// Trait used for objects that are hittable by a ray. Objects are shared between the render threads, hence `Send + Sync`
+pub trait Object: Send + Sync {
     /// # `hit`
     /// Returns true if the given `Ray` hits the object
-    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord) -> bool;
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool;
+
+    /// # `bounding_box`
+    /// Returns the `Aabb` surrounding the object or `None` if the object has no finite bounds
+    fn bounding_box(&self) -> Option<Aabb>;
+
+    /// # `pdf_value`
+    /// Returns the probability density (over solid angle) that `random` picks the given direction `Vector3` from the given origin `Vector3`.
+    /// Objects that can not be sampled as lights return 0
+    fn pdf_value(&self, _origin: &Vector3, _direction: &Vector3) -> f32 {
+        0.0
+    }
+
+    /// # `random`
+    /// Returns a random direction `Vector3` from the given origin `Vector3` towards the object
+    fn random(&self, _origin: &Vector3) -> Vector3 {
+        X_UNIT
+    }
 }
 
+mod aabb;
+mod bvh;
 mod sphere;
 mod world;
 mod rectangle;
+mod quad;
+mod disk;
+mod cuboid;
+mod axial;
+mod cylinder;
+mod cone;
+mod capsule;
+mod plane;
+mod torus;
+mod triangle;
+mod mesh;
+mod transformed;
 
 pub use self::{
+    aabb::Aabb,
+    bvh::BvhNode,
     sphere::Sphere,
     world::World,
     rectangle::{
         Axis,
         Rectangle
-    }
+    },
+    quad::Quad,
+    disk::Disk,
+    cuboid::Cuboid,
+    cylinder::Cylinder,
+    cone::Cone,
+    capsule::Capsule,
+    plane::Plane,
+    torus::Torus,
+    triangle::Triangle,
+    mesh::Mesh,
+    transformed::Transformed
 };
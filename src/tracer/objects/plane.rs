@@ -0,0 +1,66 @@
+use super::{Object, Material, Ray, HitRecord, Aabb};
+use crate::utils::{Vector3, EPSILON};
+
+/// # `Plane`
+/// An infinite flat surface through a point facing the direction of its normal. It has no bounding box so it is kept outside of the BVH
+pub struct Plane {
+    pub point: Vector3,
+    pub normal: Vector3,
+    pub material: Material,
+    axis_u: Vector3, // Directions on the plane used for the texture coordinates
+    axis_v: Vector3
+}
+
+impl Plane {
+    /// # `new`
+    /// Creates a new plane through the given point and facing the given normal as `Vector3` and its `Material`
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(point: Vector3, normal: Vector3, material: Material) -> Box<dyn Object> {
+        let normal = normal.unit();
+        let (axis_u, axis_v) = normal.basis();
+
+        Box::new(Plane {
+            point,
+            normal,
+            material,
+            axis_u,
+            axis_v
+        })
+    }
+}
+
+impl Object for Plane {
+
+    /// # `hit`
+    /// Returns true if the ray `Ray` hit the plane within the given parameter boundries t_min, t_max as `f32` and the hit_record of the ray `HitRecord`.
+    /// The texture coordinates repeat every unit along the plane, so textures tile over it forever
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
+        let denominator = self.normal.dot(ray.direction);
+        if denominator.abs() < EPSILON {
+            return false; // Ray is parallel to the plane
+        }
+
+        let t = self.normal.dot(self.point - ray.origin) / denominator;
+        if t < t_min || t > t_max {
+            return false;
+        }
+
+        let point = ray.at(t);
+        let planar = point - self.point;
+
+        hit_record.t = t;
+        hit_record.u = planar.dot(self.axis_u).rem_euclid(1.0);
+        hit_record.v = planar.dot(self.axis_v).rem_euclid(1.0);
+        hit_record.face_normal(ray, &self.normal);
+        hit_record.material = Some(&self.material);
+        hit_record.origin = point;
+
+        true
+    }
+
+    /// # `bounding_box`
+    /// The plane has no finite bounds
+    fn bounding_box(&self) -> Option<Aabb> {
+        None
+    }
+}
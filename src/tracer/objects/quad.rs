@@ -0,0 +1,99 @@
+use super::{Object, Material, Ray, HitRecord, Aabb};
+use crate::utils::{Vector3, random_f32, EPSILON, INFINITY};
+
+/// # `Quad`
+/// A parallelogram given by a corner and the two edges leaving it. Unlike `Rectangle` it can be oriented in any direction
+pub struct Quad {
+    pub corner: Vector3,
+    pub edge_u: Vector3,
+    pub edge_v: Vector3,
+    pub material: Material,
+    normal: Vector3,
+    w: Vector3, // Used to find the edge coordinates of a point on the plane
+    area: f32
+}
+
+impl Quad {
+    /// # `new`
+    /// Creates a new quad using the given corner and the two edges leaving it as `Vector3` and its `Material`.
+    /// The front of the quad is the side the cross product of the first and second edge points to
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(corner: Vector3, edge_u: Vector3, edge_v: Vector3, material: Material) -> Box<dyn Object> {
+        let n = edge_u.cross(edge_v);
+
+        Box::new(Quad {
+            corner,
+            edge_u,
+            edge_v,
+            material,
+            normal: n.unit(),
+            w: n / n.norm_squared(),
+            area: n.norm()
+        })
+    }
+}
+
+impl Object for Quad {
+
+    /// # `hit`
+    /// Returns true if the ray `Ray` hit the quad within the given parameter boundries t_min, t_max as `f32` and the hit_record of the ray `HitRecord`
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
+        let denominator = self.normal.dot(ray.direction);
+        if denominator.abs() < EPSILON {
+            return false; // Ray is parallel to the quad
+        }
+
+        let t = self.normal.dot(self.corner - ray.origin) / denominator;
+        if t < t_min || t > t_max {
+            return false;
+        }
+
+        // Coordinates of the hit point along the two edges, both are in [0, 1] inside of the quad
+        let point = ray.at(t);
+        let planar = point - self.corner;
+        let alpha = self.w.dot(planar.cross(self.edge_v));
+        let beta = self.w.dot(self.edge_u.cross(planar));
+        if !(0.0..=1.0).contains(&alpha) || !(0.0..=1.0).contains(&beta) {
+            return false;
+        }
+
+        hit_record.t = t;
+        hit_record.u = alpha;
+        hit_record.v = beta;
+        hit_record.face_normal(ray, &self.normal);
+        hit_record.material = Some(&self.material);
+        hit_record.origin = point;
+
+        true
+    }
+
+    /// # `bounding_box`
+    /// Returns the box surrounding the four corners of the quad, padded so it is not infinitely thin
+    fn bounding_box(&self) -> Option<Aabb> {
+        let corners = [self.corner, self.corner + self.edge_u, self.corner + self.edge_v, self.corner + self.edge_u + self.edge_v];
+        Some(Aabb::from_points(&corners).padded(1.0e-4))
+    }
+
+    /// # `pdf_value`
+    /// Returns the probability density of picking the given direction from the given origin, points are picked uniformly over the area of the quad
+    fn pdf_value(&self, origin: &Vector3, direction: &Vector3) -> f32 {
+        let mut hit_record = HitRecord::new_empty();
+        if !self.hit(&Ray::new(*origin, *direction), EPSILON, INFINITY, &mut hit_record) {
+            return 0.0;
+        }
+
+        let distance_squared = hit_record.t * hit_record.t * direction.norm_squared();
+        let cosine = (direction.dot(self.normal) / direction.norm()).abs();
+        if cosine < EPSILON {
+            return 0.0;
+        }
+
+        distance_squared / (cosine * self.area)
+    }
+
+    /// # `random`
+    /// Returns the direction from the given origin to a random point on the quad
+    fn random(&self, origin: &Vector3) -> Vector3 {
+        self.corner + random_f32() * self.edge_u + random_f32() * self.edge_v - *origin
+    }
+}
@@ -1,8 +1,9 @@
-use super::{Object, Material, Ray, HitRecord};
-use crate::utils::{X_UNIT, Y_UNIT, Z_UNIT};
+use super::{Object, Material, Ray, HitRecord, Aabb};
+use crate::utils::{Vector3, random_f32, X_UNIT, Y_UNIT, Z_UNIT, EPSILON, INFINITY};
 
 /// # `Axis`
 /// Enum type used to specify the axis which a rectangle is aligned to
+#[derive(Copy, Clone)]
 pub enum Axis {
     XY, XZ, YZ
 }
@@ -20,30 +21,75 @@ pub struct Rectangle {
 impl Object for Rectangle {
     /// `hit`
     /// Returns true if a ray hits the rectangle using the given boundries 
-    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord) -> bool {
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
         match self.axis { // Calcualte hit based on axis 
-            Axis::XY => {return self.hit_xy(ray, t_min, t_max, hit_record);},
-            Axis::XZ => {return self.hit_xz(ray, t_min, t_max, hit_record);},
-            Axis::YZ => {return self.hit_yz(ray, t_min, t_max, hit_record);}
+            Axis::XY => {self.hit_xy(ray, t_min, t_max, hit_record)},
+            Axis::XZ => {self.hit_xz(ray, t_min, t_max, hit_record)},
+            Axis::YZ => {self.hit_yz(ray, t_min, t_max, hit_record)}
         }
     }
+
+    /// `bounding_box`
+    /// Returns the box surrounding the rectangle, padded in the third axis so it is not infinitely thin
+    fn bounding_box(&self) -> Option<Aabb> {
+        let (min, max) = match self.axis {
+            Axis::XY => (Vector3::new(self.axis_0.0, self.axis_1.0, self.k), Vector3::new(self.axis_0.1, self.axis_1.1, self.k)),
+            Axis::XZ => (Vector3::new(self.axis_0.0, self.k, self.axis_1.0), Vector3::new(self.axis_0.1, self.k, self.axis_1.1)),
+            Axis::YZ => (Vector3::new(self.k, self.axis_0.0, self.axis_1.0), Vector3::new(self.k, self.axis_0.1, self.axis_1.1))
+        };
+
+        Some(Aabb::new(min, max).padded(1.0e-4))
+    }
+
+    /// `pdf_value`
+    /// Returns the probability density of picking the given direction from the given origin, points are picked uniformly over the area of the rectangle
+    fn pdf_value(&self, origin: &Vector3, direction: &Vector3) -> f32 {
+        let mut hit_record = HitRecord::new_empty();
+        if !self.hit(&Ray::new(*origin, *direction), EPSILON, INFINITY, &mut hit_record) {
+            return 0.0;
+        }
+
+        let area = (self.axis_0.1 - self.axis_0.0) * (self.axis_1.1 - self.axis_1.0);
+        let distance_squared = hit_record.t * hit_record.t * direction.norm_squared();
+        let cosine = (direction.dot(hit_record.normal) / direction.norm()).abs();
+        if cosine < EPSILON {
+            return 0.0;
+        }
+
+        distance_squared / (cosine * area)
+    }
+
+    /// `random`
+    /// Returns the direction from the given origin to a random point on the rectangle
+    fn random(&self, origin: &Vector3) -> Vector3 {
+        let a = self.axis_0.0 + random_f32() * (self.axis_0.1 - self.axis_0.0);
+        let b = self.axis_1.0 + random_f32() * (self.axis_1.1 - self.axis_1.0);
+
+        let point = match self.axis {
+            Axis::XY => Vector3::new(a, b, self.k),
+            Axis::XZ => Vector3::new(a, self.k, b),
+            Axis::YZ => Vector3::new(self.k, a, b)
+        };
+        point - *origin
+    }
 }
 
 impl Rectangle {
     /// # `new`
     /// Creates a new rectangle and returns it using the given `Axis` of alignment, the boundries in the first and second axis as `(f32, f32)` then the depth in the third as `f32`.
     /// Takes the rectangle's `Material` as well
+    #[allow(clippy::new_ret_no_self)]
     pub fn new(axis: Axis, axis_0: (f32, f32), axis_1: (f32, f32), k: f32, material: Material) -> Box<dyn Object> {
         Box::new (Rectangle {
-            axis: axis,
-            axis_0: axis_0,
-            axis_1: axis_1,
-            k: k,
-            material: material,
+            axis,
+            axis_0,
+            axis_1,
+            k,
+            material,
         })
     }
 
-    fn hit_xy(&self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord) -> bool {
+    fn hit_xy<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
         let t = (self.k - ray.origin.z) / ray.direction.z;
         if t < t_min || t > t_max {return false;}
 
@@ -52,14 +98,16 @@ impl Rectangle {
         if x < self.axis_0.0 || x > self.axis_0.1 || y < self.axis_1.0 || y > self.axis_1.1 {return false;}
         
         hit_record.t = t;
+        hit_record.u = (x - self.axis_0.0) / (self.axis_0.1 - self.axis_0.0);
+        hit_record.v = (y - self.axis_1.0) / (self.axis_1.1 - self.axis_1.0);
         hit_record.face_normal(ray, &Z_UNIT);
-        hit_record.material = Some(self.material);
+        hit_record.material = Some(&self.material);
         hit_record.origin = ray.at(t);
         
         true
     }
 
-    fn hit_xz(&self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord) -> bool {
+    fn hit_xz<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
         let t = (self.k - ray.origin.y) / ray.direction.y;
         if t < t_min || t > t_max {return false;}
 
@@ -68,14 +116,16 @@ impl Rectangle {
         if x < self.axis_0.0 || x > self.axis_0.1 || z < self.axis_1.0 || z > self.axis_1.1 {return false;}
         
         hit_record.t = t;
+        hit_record.u = (x - self.axis_0.0) / (self.axis_0.1 - self.axis_0.0);
+        hit_record.v = (z - self.axis_1.0) / (self.axis_1.1 - self.axis_1.0);
         hit_record.face_normal(ray, &Y_UNIT);
-        hit_record.material = Some(self.material);
+        hit_record.material = Some(&self.material);
         hit_record.origin = ray.at(t);
         
         true
     }
 
-    fn hit_yz(&self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord) -> bool {
+    fn hit_yz<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
         let t = (self.k - ray.origin.x) / ray.direction.x;
         if t < t_min || t > t_max {return false;}
 
@@ -84,8 +134,10 @@ impl Rectangle {
         if y < self.axis_0.0 || y > self.axis_0.1 || z < self.axis_1.0 || z > self.axis_1.1 {return false;}
         
         hit_record.t = t;
+        hit_record.u = (y - self.axis_0.0) / (self.axis_0.1 - self.axis_0.0);
+        hit_record.v = (z - self.axis_1.0) / (self.axis_1.1 - self.axis_1.0);
         hit_record.face_normal(ray, &X_UNIT);
-        hit_record.material = Some(self.material);
+        hit_record.material = Some(&self.material);
         hit_record.origin = ray.at(t);
         
         true
@@ -1,5 +1,6 @@
-use crate::utils::Vector3;
-use super::{Object, Ray, HitRecord, Material};
+use crate::utils::{Vector3, random_f32, EPSILON, INFINITY};
+use std::f32::consts::PI;
+use super::{Object, Ray, HitRecord, Material, Aabb};
 
 /// # `Sphere`
 /// A good ol' Sphere with a center and a radius
@@ -12,20 +13,40 @@ pub struct Sphere {
 impl Sphere {
     /// # `new`
     /// Creates a new sphere using the given center as `Vector3` and radius as `f32`
+    #[allow(clippy::new_ret_no_self)]
     pub fn new(center: Vector3, radius: f32, material: Material) -> Box<dyn Object> {
         Box::new ( Sphere {
-            center: center,
-            radius: radius,
-            material: material
+            center,
+            radius,
+            material
         } )
     }
 }
 
+impl Sphere {
+    /// # `uv`
+    /// Returns the texture coordinates (u, v) as `(f32, f32)` of a point on the unit sphere given as `Vector3`.
+    /// u goes around the y axis starting at -x and v goes from the bottom to the top
+    fn uv(point: &Vector3) -> (f32, f32) {
+        let theta = (-point.y).clamp(-1.0, 1.0).acos();
+        let phi = (-point.z).atan2(point.x) + PI;
+
+        (phi / (2.0 * PI), theta / PI)
+    }
+
+    /// # `cone_height`
+    /// Returns 1 - cos θ of the cone of directions covering a sphere, given the squared ratio of its radius to its distance as `f32`.
+    /// Written as (r² / d²) / (1 + cos θ) so that it does not round to 0 for far away spheres
+    fn cone_height(ratio_squared: f32) -> f32 {
+        ratio_squared / (1.0 + (1.0 - ratio_squared).sqrt())
+    }
+}
+
 impl Object for Sphere {
 
     /// # `hit`
     /// Returns true if the ray `Ray` hit the object within the given parameter boundries t_min, t_max as `f32` and the hit_record of the ray `HitRecord`
-    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord) -> bool {
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
         let orig_center = ray.origin - self.center;
         let a = ray.direction.norm_squared();
         let b_half = orig_center.dot(ray.direction);
@@ -50,8 +71,53 @@ impl Object for Sphere {
         hit_record.origin = ray.at(hit_record.t);
         let normal = (hit_record.origin - self.center) / self.radius;
         hit_record.face_normal(ray, &normal);
-        hit_record.material = Some(self.material);
+        (hit_record.u, hit_record.v) = Sphere::uv(&normal);
+        hit_record.material = Some(&self.material);
+
+        true
+    }
+
+    /// # `bounding_box`
+    /// Returns the box surrounding the sphere
+    fn bounding_box(&self) -> Option<Aabb> {
+        let r = Vector3::new(self.radius.abs(), self.radius.abs(), self.radius.abs());
+        Some(Aabb::new(self.center - r, self.center + r))
+    }
+
+    /// # `pdf_value`
+    /// Returns the probability density of picking the given direction from the given origin, which is uniform over the cone of directions the sphere covers.
+    /// The density is 0 if the direction misses the sphere or the origin is inside of it
+    fn pdf_value(&self, origin: &Vector3, direction: &Vector3) -> f32 {
+        let mut hit_record = HitRecord::new_empty();
+        if !self.hit(&Ray::new(*origin, *direction), EPSILON, INFINITY, &mut hit_record) {
+            return 0.0;
+        }
+
+        let distance_squared = (self.center - *origin).norm_squared();
+        let radius_squared = self.radius * self.radius;
+        if distance_squared <= radius_squared {
+            return 0.0;
+        }
+
+        let pdf = 1.0 / (2.0 * PI * Self::cone_height(radius_squared / distance_squared));
+        if pdf.is_finite() {pdf} else {0.0}
+    }
+
+    /// # `random`
+    /// Returns a random direction from the given origin inside of the cone of directions the sphere covers
+    fn random(&self, origin: &Vector3) -> Vector3 {
+        let to_center = self.center - *origin;
+        let distance_squared = to_center.norm_squared();
+        let height = Self::cone_height((self.radius * self.radius / distance_squared).min(1.0));
+
+        // 1 - cos θ is kept instead of cos θ, which would round to 1 for far away spheres
+        let one_minus_cos = random_f32() * height;
+        let cos_theta = 1.0 - one_minus_cos;
+        let sin_theta = (one_minus_cos * (2.0 - one_minus_cos)).sqrt();
+        let phi = 2.0 * PI * random_f32();
 
-        return true;
+        let w = to_center.unit();
+        let (u, v) = w.basis();
+        sin_theta * phi.cos() * u + sin_theta * phi.sin() * v + cos_theta * w
     }
 }
\ No newline at end of file
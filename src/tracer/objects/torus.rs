@@ -0,0 +1,88 @@
+use super::{Object, Material, Ray, HitRecord, Aabb};
+use super::axial::{AxisFrame, angle_u, fill_record};
+use crate::utils::{Vector3, solve_quartic};
+use std::f32::consts::PI;
+
+/// # `Torus`
+/// A ring around an axis through its center. The major radius is the distance from the center to the middle of the tube and the minor radius is the radius of the tube
+pub struct Torus {
+    pub center: Vector3,
+    pub major_radius: f32,
+    pub minor_radius: f32,
+    pub material: Material,
+    frame: AxisFrame
+}
+
+impl Torus {
+    /// # `new`
+    /// Creates a new torus using its center and the axis it goes around as `Vector3`, the major and minor radius as `f32` and its `Material`
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(center: Vector3, axis: Vector3, major_radius: f32, minor_radius: f32, material: Material) -> Box<dyn Object> {
+        Box::new(Torus {
+            center,
+            major_radius,
+            minor_radius,
+            material,
+            frame: AxisFrame::new(center, center + axis)
+        })
+    }
+}
+
+impl Object for Torus {
+
+    /// # `hit`
+    /// Returns true if the ray `Ray` hit the torus within the given parameter boundries t_min, t_max as `f32` and the hit_record of the ray `HitRecord`.
+    /// The ray is put into the frame of the torus (axis along z) where (x² + y² + z² + R² - r²)² = 4 R² (x² + y²) is solved for t in f64.
+    /// u goes around the axis and v around the tube
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
+        let (o, d) = self.frame.local_ray(ray);
+        let speed = d.norm() as f64;
+        let o = [o.x as f64, o.y as f64, o.z as f64];
+        let d = [d.x as f64 / speed, d.y as f64 / speed, d.z as f64 / speed];
+        let dot = |_a: &[f64; 3], _b: &[f64; 3]| _a[0] * _b[0] + _a[1] * _b[1] + _a[2] * _b[2];
+        let (major, minor) = (self.major_radius as f64, self.minor_radius as f64);
+
+        // Start the ray where it enters the sphere around the torus, far away starts lose precision in the quartic
+        let bound = major + minor;
+        let b_half = dot(&o, &d);
+        let discriminant = b_half * b_half - (dot(&o, &o) - bound * bound);
+        if discriminant < 0.0 {
+            return false;
+        }
+        let start = (-b_half - discriminant.sqrt()).max(0.0);
+        let o = [o[0] + start * d[0], o[1] + start * d[1], o[2] + start * d[2]];
+
+        let major_sq = major * major;
+        let n = dot(&o, &d);
+        let k = dot(&o, &o) + major_sq - minor * minor;
+        let roots = solve_quartic(
+            4.0 * n,
+            2.0 * k + 4.0 * n * n - 4.0 * major_sq * (d[0] * d[0] + d[1] * d[1]),
+            4.0 * n * k - 8.0 * major_sq * (o[0] * d[0] + o[1] * d[1]),
+            k * k - 4.0 * major_sq * (o[0] * o[0] + o[1] * o[1])
+        );
+
+        // Roots are distances along the unit direction from the new start
+        let Some(root) = roots.into_iter().find(|_root| {
+            let t = ((start + _root) / speed) as f32;
+            t >= t_min && t <= t_max
+        }) else {
+            return false;
+        };
+
+        let point = Vector3::new((o[0] + root * d[0]) as f32, (o[1] + root * d[1]) as f32, (o[2] + root * d[2]) as f32);
+        let ring = (point.x * point.x + point.y * point.y).sqrt();
+        let core = if ring > 0.0 {Vector3::new(point.x, point.y, 0.0) * (self.major_radius / ring)} else {Vector3::new(self.major_radius, 0.0, 0.0)};
+        let normal = (point - core) / self.minor_radius;
+        let v = (point.z.atan2(ring - self.major_radius) + PI) / (2.0 * PI);
+
+        fill_record(ray, &self.frame, (((start + root) / speed) as f32, normal, angle_u(point.x, point.y), v), &self.material, hit_record);
+        true
+    }
+
+    /// # `bounding_box`
+    /// Returns the box surrounding the ring of the major radius grown by the minor radius
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(self.frame.disk_bounds(self.center, self.major_radius).padded(self.minor_radius))
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+use crate::utils::{Vector3, Matrix4};
+use super::{Object, Ray, HitRecord, Aabb};
+
+/// # `Transformed`
+/// Wrapper that places any object using an affine transform. Rays are moved into the space of the inner object and the hit is moved back.
+/// The inner object is shared so the same geometry can be placed several times without copying it
+pub struct Transformed {
+    pub inner: Arc<dyn Object>,
+    matrix: Matrix4,
+    inverse: Matrix4,
+    bbox: Option<Aabb>
+}
+
+impl Transformed {
+    /// # `new`
+    /// Creates a new transformed object from the shared inner object `Arc<dyn Object>` and the transform `Matrix4`. The matrix must be invertible
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(inner: Arc<dyn Object>, matrix: Matrix4) -> Box<dyn Object> {
+        let inverse = matrix.inverse().expect("Transform matrix is not invertible");
+
+        // The box around the 8 transformed corners of the inner box
+        let bbox = inner.bounding_box().map(|_bbox| {
+            let corners: Vec<Vector3> = (0..8)
+                .map(|i| matrix.transform_point(Vector3::new(
+                    if i & 1 == 0 {_bbox.min.x} else {_bbox.max.x},
+                    if i & 2 == 0 {_bbox.min.y} else {_bbox.max.y},
+                    if i & 4 == 0 {_bbox.min.z} else {_bbox.max.z}
+                )))
+                .collect();
+            Aabb::from_points(&corners)
+        });
+
+        Box::new(Transformed {
+            inner,
+            matrix,
+            inverse,
+            bbox
+        })
+    }
+}
+
+impl Object for Transformed {
+
+    /// # `hit`
+    /// Returns true if the ray `Ray` hit the inner object within the given parameter boundries t_min, t_max as `f32` and the hit_record of the ray `HitRecord`.
+    /// The direction of the ray is not normalised in object space so the parameter t is the same in both spaces
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
+        let local_ray = Ray::new(self.inverse.transform_point(ray.origin), self.inverse.transform_vector(ray.direction));
+
+        if !self.inner.hit(&local_ray, t_min, t_max, hit_record) {
+            return false;
+        }
+
+        // Normals transform with the inverse transpose
+        hit_record.origin = self.matrix.transform_point(hit_record.origin);
+        hit_record.normal = self.inverse.transpose().transform_vector(hit_record.normal).unit();
+
+        true
+    }
+
+    /// # `bounding_box`
+    /// Returns the box surrounding the transformed inner object
+    fn bounding_box(&self) -> Option<Aabb> {
+        self.bbox
+    }
+}
@@ -0,0 +1,102 @@
+use crate::utils::{Vector3, EPSILON};
+use super::{Object, Ray, HitRecord, Material, Aabb};
+
+/// # `Triangle`
+/// A single triangle given by its three vertices. Optional per-vertex normals are interpolated over the triangle for smooth shading
+pub struct Triangle {
+    pub vertices: [Vector3; 3],
+    pub normals: Option<[Vector3; 3]>,
+    pub material: Material
+}
+
+impl Triangle {
+    /// # `new`
+    /// Creates a new triangle using the given vertices as `[Vector3; 3]`, optional vertex normals as `Option<[Vector3; 3]>` and its `Material`
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(vertices: [Vector3; 3], normals: Option<[Vector3; 3]>, material: Material) -> Box<dyn Object> {
+        Box::new(Triangle {
+            vertices,
+            normals,
+            material
+        })
+    }
+}
+
+impl Object for Triangle {
+
+    /// # `hit`
+    /// Returns true if the ray `Ray` hit the triangle within the given parameter boundries t_min, t_max as `f32` and the hit_record of the ray `HitRecord`
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
+        match intersect(ray, &self.vertices, t_min, t_max) {
+            Some((t, u, v)) => {
+                fill_record(ray, &self.vertices, self.normals.as_ref(), (t, u, v), &self.material, hit_record);
+                true
+            }
+            None => false
+        }
+    }
+
+    /// # `bounding_box`
+    /// Returns the box surrounding the triangle
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(bounds(&self.vertices))
+    }
+}
+
+/// # `intersect`
+/// Möller–Trumbore ray-triangle intersection. Returns the ray parameter t and the barycentric coordinates u, v of the hit as `(f32, f32, f32)` if the ray hits the triangle within [t_min, t_max]
+pub(super) fn intersect(ray: &Ray, vertices: &[Vector3; 3], t_min: f32, t_max: f32) -> Option<(f32, f32, f32)> {
+    let edge_1 = vertices[1] - vertices[0];
+    let edge_2 = vertices[2] - vertices[0];
+
+    let p = ray.direction.cross(edge_2);
+    let det = edge_1.dot(p);
+    if det.abs() < EPSILON {
+        return None; // Ray is parallel to the triangle
+    }
+
+    let inv_det = 1.0 / det;
+    let s = ray.origin - vertices[0];
+    let u = s.dot(p) * inv_det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let q = s.cross(edge_1);
+    let v = ray.direction.dot(q) * inv_det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    let t = edge_2.dot(q) * inv_det;
+    if t < t_min || t > t_max {
+        return None;
+    }
+
+    Some((t, u, v))
+}
+
+/// # `fill_record`
+/// Writes the hit at the given `(t, u, v)` into the `HitRecord`, the barycentric coordinates u, v are used as texture coordinates. The front face is decided by the geometric normal while the stored normal is the interpolated vertex normal (if given)
+pub(super) fn fill_record<'a>(ray: &Ray, vertices: &[Vector3; 3], normals: Option<&[Vector3; 3]>, hit: (f32, f32, f32), material: &'a Material, hit_record: &mut HitRecord<'a>) {
+    let (t, u, v) = hit;
+    let geometric = (vertices[1] - vertices[0]).cross(vertices[2] - vertices[0]).unit();
+
+    hit_record.t = t;
+    hit_record.u = u;
+    hit_record.v = v;
+    hit_record.origin = ray.at(t);
+    hit_record.face_normal(ray, &geometric);
+    hit_record.material = Some(material);
+
+    if let Some(n) = normals {
+        let shading = ((1.0 - u - v) * n[0] + u * n[1] + v * n[2]).unit();
+        hit_record.normal = if hit_record.front_face {shading} else {-shading};
+    }
+}
+
+/// # `bounds`
+/// Returns the padded box surrounding the given vertices
+pub(super) fn bounds(vertices: &[Vector3; 3]) -> Aabb {
+    Aabb::from_points(vertices).padded(1.0e-4)
+}
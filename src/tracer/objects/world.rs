@@ -1,10 +1,12 @@
-use super::{Object, HitRecord, Ray};
-use crate::utils::{Colour, ORIGIN};
+use super::{Object, HitRecord, Ray, Aabb, BvhNode};
+use crate::utils::{Colour, Vector3, random_f32, ORIGIN};
 
 /// # `World`
 /// The entire world that is rendered. Holds a vector of objects that are hit by the rays
+/// and a vector of the lights that are sampled directly (copies of emitting objects that are also in `objects`)
 pub struct World {
     pub objects: Vec<Box<dyn Object>>,
+    pub lights: Vec<Box<dyn Object>>,
     pub background: Colour
 }
 
@@ -14,16 +16,55 @@ impl World {
     pub fn new_empty() -> World {
         World {
             objects: Vec::new(),
+            lights: Vec::new(),
             background: ORIGIN // black background
         }
     }
+
+    /// # `build_bvh`
+    /// Groups all bounded objects of the world into a bounding volume hierarchy so that hitting the world is no longer a linear scan over all objects.
+    /// Objects without a bounding box are kept outside of the hierarchy
+    pub fn build_bvh(&mut self) {
+        let (bounded, unbounded): (Vec<_>, Vec<_>) = self.objects
+            .drain(..)
+            .partition(|_object| _object.bounding_box().is_some());
+
+        self.objects = unbounded;
+        match bounded.len() {
+            0 => {}
+            1 => self.objects.extend(bounded),
+            _ => self.objects.push(Box::new(BvhNode::new(bounded)))
+        }
+    }
+
+    /// # `light_pdf`
+    /// Returns the probability density that `sample_light` picks the given direction `Vector3` from the given origin `Vector3`
+    pub fn light_pdf(&self, origin: &Vector3, direction: &Vector3) -> f32 {
+        if self.lights.is_empty() {
+            return 0.0;
+        }
+
+        let sum: f32 = self.lights.iter().map(|_light| _light.pdf_value(origin, direction)).sum();
+        sum / self.lights.len() as f32
+    }
+
+    /// # `sample_light`
+    /// Returns a random direction `Vector3` from the given origin `Vector3` towards one of the lights (picked uniformly) or `None` if there are no lights
+    pub fn sample_light(&self, origin: &Vector3) -> Option<Vector3> {
+        if self.lights.is_empty() {
+            return None;
+        }
+
+        let idx = ((random_f32() * self.lights.len() as f32) as usize).min(self.lights.len() - 1);
+        Some(self.lights[idx].random(origin))
+    }
 }
 
 impl Object for World {
 
     /// # `hit`
     /// Returns true if the ray `Ray` hit the objects in the world within the given parameter boundries t_min, t_max as `f32` and the hit_record of the ray `HitRecord`
-    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord) -> bool {
+    fn hit<'a>(&'a self, ray: &Ray, t_min: f32, t_max: f32, hit_record: &mut HitRecord<'a>) -> bool {
         let mut temp: HitRecord = HitRecord::new_empty();
         let mut hit_anything = false;
         let mut closest = t_max;
@@ -38,4 +79,13 @@ impl Object for World {
 
         hit_anything
     }
+
+    /// # `bounding_box`
+    /// Returns the box surrounding all objects in the world or `None` if the world is empty or has an unbounded object
+    fn bounding_box(&self) -> Option<Aabb> {
+        let mut boxes = self.objects.iter().map(|_object| _object.bounding_box());
+        let first = boxes.next()??;
+
+        boxes.try_fold(first, |_acc, _bbox| Some(Aabb::surrounding(&_acc, &_bbox?)))
+    }
 }
\ No newline at end of file
@@ -0,0 +1,176 @@
+use std::{path::Path, sync::Arc};
+use crate::utils::{Colour, Perlin, Vector3, srgb_to_linear};
+
+/// # `Texture`
+/// Trait used for anything that gives a colour at a point of a surface. Evaluated using the texture coordinates u, v and the hit point
+pub trait Texture: Send + Sync {
+    /// # `value`
+    /// Returns the `Colour` of the texture at the texture coordinates u, v as `f32` and the point as `Vector3`
+    fn value(&self, u: f32, v: f32, point: &Vector3) -> Colour;
+}
+
+/// # `SolidColour`
+/// Texture with the same colour everywhere
+pub struct SolidColour {
+    pub colour: Colour
+}
+
+impl SolidColour {
+    /// # `new`
+    /// Creates a new texture of the given `Colour`
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(colour: Colour) -> Arc<dyn Texture> {
+        Arc::new(SolidColour {
+            colour
+        })
+    }
+}
+
+impl Texture for SolidColour {
+    fn value(&self, _u: f32, _v: f32, _point: &Vector3) -> Colour {
+        self.colour
+    }
+}
+
+/// # `Checker`
+/// 3D checker pattern alternating between two textures in cubes with a side of `size`
+pub struct Checker {
+    pub size: f32,
+    pub even: Arc<dyn Texture>,
+    pub odd: Arc<dyn Texture>
+}
+
+impl Checker {
+    /// # `new`
+    /// Creates a new checker texture with cubes of the given size as `f32` alternating between the given even and odd textures
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(size: f32, even: Arc<dyn Texture>, odd: Arc<dyn Texture>) -> Arc<dyn Texture> {
+        Arc::new(Checker {
+            size,
+            even,
+            odd
+        })
+    }
+}
+
+impl Texture for Checker {
+    fn value(&self, u: f32, v: f32, point: &Vector3) -> Colour {
+        // Small offset so surfaces lying exactly on a cube border (like a floor at 0) do not flicker between the two textures
+        let cell = |_coord: f32| (_coord / self.size + 1.0e-3).floor() as i64;
+        let parity = cell(point.x) + cell(point.y) + cell(point.z);
+
+        if parity.rem_euclid(2) == 0 {
+            self.even.value(u, v, point)
+        } else {
+            self.odd.value(u, v, point)
+        }
+    }
+}
+
+/// # `ImageTexture`
+/// Texture that maps an image over the texture coordinates. (0, 0) is the bottom left of the image and (1, 1) the top right
+pub struct ImageTexture {
+    width: usize,
+    height: usize,
+    pixels: Vec<Colour>
+}
+
+impl ImageTexture {
+    /// # `load`
+    /// Loads the image file at the given path (using the Image crate) as a texture.
+    /// The colours are decoded from sRGB into linear values
+    pub fn load(path: &Path) -> image::ImageResult<Arc<dyn Texture>> {
+        let img = image::open(path)?.to_rgb8();
+
+        let pixels = img
+            .pixels()
+            .map(|_pixel| {
+                let channel = |_byte: u8| srgb_to_linear(_byte as f32 / 255.0);
+                Colour::new(channel(_pixel[0]), channel(_pixel[1]), channel(_pixel[2]))
+            })
+            .collect();
+
+        Ok(Arc::new(ImageTexture {
+            width: img.width() as usize,
+            height: img.height() as usize,
+            pixels
+        }))
+    }
+}
+
+impl Texture for ImageTexture {
+    fn value(&self, u: f32, v: f32, _point: &Vector3) -> Colour {
+        let u = u.clamp(0.0, 1.0);
+        let v = 1.0 - v.clamp(0.0, 1.0); // Image rows go from top to bottom
+
+        let i = ((u * self.width as f32) as usize).min(self.width - 1);
+        let j = ((v * self.height as f32) as usize).min(self.height - 1);
+
+        self.pixels[j * self.width + i]
+    }
+}
+
+/// # `NoisePattern`
+/// Enum type to specify how the Perlin noise of a `NoiseTexture` is turned into a pattern
+#[derive(Copy, Clone)]
+pub enum NoisePattern {
+    Noise,
+    Turbulence,
+    Marble,
+    Wood
+}
+
+// Amount of octaves used for turbulence
+const TURBULENCE_DEPTH: usize = 7;
+
+/// # `NoiseTexture`
+/// Procedural texture made from seeded Perlin noise. The pattern blends between the `low` and `high` textures
+pub struct NoiseTexture {
+    pub perlin: Perlin,
+    pub scale: f32,
+    pub pattern: NoisePattern,
+    pub low: Arc<dyn Texture>,
+    pub high: Arc<dyn Texture>
+}
+
+impl NoiseTexture {
+    /// # `new`
+    /// Creates a new noise texture using the `NoisePattern`, the scale (frequency) of the noise as `f32`, the seed of the noise as `u64`
+    /// and the two textures the pattern blends between
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(pattern: NoisePattern, scale: f32, seed: u64, low: Arc<dyn Texture>, high: Arc<dyn Texture>) -> Arc<dyn Texture> {
+        Arc::new(NoiseTexture {
+            perlin: Perlin::new(seed),
+            scale,
+            pattern,
+            low,
+            high
+        })
+    }
+
+    /// # `amount`
+    /// Returns the value of the pattern at the given point `Vector3` in the range [0, 1]
+    pub fn amount(&self, point: &Vector3) -> f32 {
+        let p = self.scale * *point;
+
+        let amount = match self.pattern {
+            NoisePattern::Noise => 0.5 * (1.0 + self.perlin.noise(&p)),
+            NoisePattern::Turbulence => self.perlin.turbulence(&p, TURBULENCE_DEPTH),
+            NoisePattern::Marble => 0.5 * (1.0 + (p.z + 10.0 * self.perlin.turbulence(&p, TURBULENCE_DEPTH)).sin()),
+            NoisePattern::Wood => {
+                // Rings around the y axis disturbed by the noise
+                let rings = (p.x * p.x + p.z * p.z).sqrt() + 2.0 * self.perlin.noise(&p);
+                rings - rings.floor()
+            }
+        };
+
+        amount.clamp(0.0, 1.0)
+    }
+}
+
+impl Texture for NoiseTexture {
+    fn value(&self, u: f32, v: f32, point: &Vector3) -> Colour {
+        let t = self.amount(point);
+        (1.0 - t) * self.low.value(u, v, point) + t * self.high.value(u, v, point)
+    }
+}
@@ -0,0 +1,174 @@
+use super::{Colour, Image};
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+
+// Start of every checkpoint file, followed by the version of the format
+const MAGIC: &[u8; 4] = b"RTCK";
+const VERSION: u32 = 2;
+
+// Size of the header (magic, version, hash, width and height) and of every pixel (sum, squared luminance and count) in bytes
+const HEADER_BYTES: u64 = 32;
+const PIXEL_BYTES: u64 = 20;
+
+// Luminance below which the error of a pixel is no longer relative but absolute
+const MIN_LUMINANCE: f32 = 0.1;
+
+/// # `SampleBuffer`
+/// Holds the sum of all samples, the sum of their squared luminance and the amount of samples of every pixel of a render together with the hash of the scene they belong to.
+/// Can be saved to and loaded from a checkpoint file so that renders can be resumed
+pub struct SampleBuffer {
+    pub hash: u64,
+    width: usize,
+    height: usize,
+    pub sums: Vec<Colour>,
+    pub squares: Vec<f32>,
+    pub counts: Vec<u32>
+}
+
+impl SampleBuffer {
+    /// # `new`
+    /// Creates an empty buffer (no samples) for an image of the given width and height as `usize` of the scene with the given hash as `u64`
+    pub fn new(width: usize, height: usize, hash: u64) -> SampleBuffer {
+        SampleBuffer {
+            hash,
+            width,
+            height,
+            sums: vec![Colour::new(0.0, 0.0, 0.0); width * height],
+            squares: vec![0.0; width * height],
+            counts: vec![0; width * height]
+        }
+    }
+
+    /// # `min_count`
+    /// Returns the least amount of samples any pixel has
+    pub fn min_count(&self) -> usize {
+        self.counts.iter().min().copied().unwrap_or(0) as usize
+    }
+
+    /// # `mean_count`
+    /// Returns the average amount of samples of the pixels
+    pub fn mean_count(&self) -> f32 {
+        self.counts.iter().map(|_count| *_count as f32).sum::<f32>() / self.counts.len().max(1) as f32
+    }
+
+    /// # `error`
+    /// Returns the estimated error of the average of the pixel at the given index as `usize`.
+    /// This is the standard error of its luminance relative to the luminance (at least `MIN_LUMINANCE` so dark pixels do not need endless samples)
+    pub fn error(&self, idx: usize) -> f32 {
+        let count = self.counts[idx] as f32;
+        if count < 2.0 {
+            return f32::INFINITY;
+        }
+
+        let mean = Colour::luminance(&self.sums[idx]) / count;
+        let variance = ((self.squares[idx] - mean * mean * count) / (count - 1.0)).max(0.0);
+        (variance / count).sqrt() / mean.max(MIN_LUMINANCE)
+    }
+
+    /// # `average`
+    /// Returns the image whose pixels are the average of their samples, pixels without samples are black
+    pub fn average(&self) -> Image {
+        let mut image = Image::new(self.width, self.height);
+        for ((pixel, sum), count) in image.pixels.iter_mut().zip(self.sums.iter()).zip(self.counts.iter()) {
+            if *count > 0 {
+                *pixel = *sum / *count as f32;
+            }
+        }
+
+        image
+    }
+
+    /// # `heatmap`
+    /// Returns an image of the amount of samples of every pixel relative to the given most samples as `usize`,
+    /// going from black (no samples) over red and yellow to white (most samples)
+    pub fn heatmap(&self, max_samples: usize) -> Image {
+        let mut image = Image::new(self.width, self.height);
+        for (pixel, count) in image.pixels.iter_mut().zip(self.counts.iter()) {
+            let t = 3.0 * (*count as f32 / max_samples.max(1) as f32).min(1.0);
+            *pixel = Colour::new(t.min(1.0), (t - 1.0).clamp(0.0, 1.0), (t - 2.0).clamp(0.0, 1.0));
+        }
+
+        image
+    }
+
+    /// # `save`
+    /// Saves the buffer as a checkpoint file at the given path as `&str`. The file is written next to it first and then moved, so a crash while saving keeps the old checkpoint
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let temp_path = format!("{}.tmp", path);
+        {
+            let mut file = BufWriter::new(File::create(&temp_path)?);
+            file.write_all(MAGIC)?;
+            file.write_all(&VERSION.to_le_bytes())?;
+            file.write_all(&self.hash.to_le_bytes())?;
+            file.write_all(&(self.width as u64).to_le_bytes())?;
+            file.write_all(&(self.height as u64).to_le_bytes())?;
+
+            for idx in 0..self.counts.len() {
+                let sum = self.sums[idx];
+                for value in [sum.x, sum.y, sum.z, self.squares[idx]] {
+                    file.write_all(&value.to_le_bytes())?;
+                }
+                file.write_all(&self.counts[idx].to_le_bytes())?;
+            }
+            file.flush()?;
+        }
+
+        fs::rename(temp_path, path)
+    }
+
+    /// # `load`
+    /// Loads the checkpoint file at the given path as `&str` if it belongs to an image of the given width and height as `usize` of the scene with the given hash as `u64`.
+    /// The header is checked before anything is allocated, so a file of another scene or a broken file gives an `InvalidData` error
+    pub fn load(path: &str, width: usize, height: usize, hash: u64) -> io::Result<SampleBuffer> {
+        let file = File::open(path)?;
+        let file_len = file.metadata()?.len();
+        let mut file = BufReader::new(file);
+
+        let mut magic = [0u8; 4];
+        file.read_exact(&mut magic)?;
+        if &magic != MAGIC || read_u32(&mut file)? != VERSION {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "not a checkpoint file of this version"));
+        }
+
+        if read_u64(&mut file)? != hash || read_u64(&mut file)? != width as u64 || read_u64(&mut file)? != height as u64 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "the checkpoint belongs to another scene, resolution or seed"));
+        }
+
+        let expected_len = (width as u64).checked_mul(height as u64)
+            .and_then(|_pixels| _pixels.checked_mul(PIXEL_BYTES))
+            .and_then(|_bytes| _bytes.checked_add(HEADER_BYTES));
+        if expected_len != Some(file_len) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "the checkpoint file has the wrong size"));
+        }
+
+        let mut buffer = SampleBuffer::new(width, height, hash);
+        for idx in 0..width * height {
+            let x = f32::from_bits(read_u32(&mut file)?);
+            let y = f32::from_bits(read_u32(&mut file)?);
+            let z = f32::from_bits(read_u32(&mut file)?);
+            buffer.sums[idx] = Colour::new(x, y, z);
+            buffer.squares[idx] = f32::from_bits(read_u32(&mut file)?);
+            buffer.counts[idx] = read_u32(&mut file)?;
+        }
+
+        Ok(buffer)
+    }
+}
+
+/// # `fnv1a`
+/// Returns the 64-bit FNV-1a hash of the given bytes
+pub fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf29ce484222325, |_hash, _byte| (_hash ^ *_byte as u64).wrapping_mul(0x100000001b3))
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
@@ -0,0 +1,107 @@
+use super::Colour;
+
+// White point of the extended Reinhard operator when none is given
+pub const DEFAULT_WHITE: f32 = 4.0;
+
+/// # `ToneMap`
+/// Enum type to specify how linear light values are compressed into the displayable range (0.0, 1.0)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ToneMap {
+    Clamp,
+    Reinhard,
+    ExtendedReinhard,
+    Aces
+}
+
+impl ToneMap {
+    /// # `from_name`
+    /// Returns the tone mapping operator with the given name as `&str` (`clamp`, `reinhard`, `extended` or `aces`), `None` if there is no such operator
+    pub fn from_name(name: &str) -> Option<ToneMap> {
+        match name {
+            "clamp" => Some(ToneMap::Clamp),
+            "reinhard" => Some(ToneMap::Reinhard),
+            "extended" => Some(ToneMap::ExtendedReinhard),
+            "aces" => Some(ToneMap::Aces),
+            _ => None
+        }
+    }
+}
+
+/// # `Film`
+/// Struct that turns the linear light values of a render into displayable colours. Holds the tone mapping operator, the exposure in stops
+/// and the white point (the smallest luminance that becomes pure white) used by the extended Reinhard operator
+#[derive(Debug, Clone, Copy)]
+pub struct Film {
+    pub tone_map: ToneMap,
+    pub exposure: f32,
+    pub white: f32
+}
+
+impl Default for Film {
+    fn default() -> Film {
+        Film {
+            tone_map: ToneMap::Clamp,
+            exposure: 0.0,
+            white: DEFAULT_WHITE
+        }
+    }
+}
+
+impl Film {
+    /// # `develop`
+    /// Takes a linear colour as `Colour`, applies the exposure and tone mapping and returns the sRGB encoded colour in the range (0.0, 1.0)
+    pub fn develop(&self, colour: &Colour) -> Colour {
+        let exposed = *colour * 2f32.powf(self.exposure);
+        let exposed = Colour::new(exposed.x.max(0.0), exposed.y.max(0.0), exposed.z.max(0.0));
+
+        let mapped = match self.tone_map {
+            ToneMap::Clamp => exposed,
+            ToneMap::Reinhard => Self::scale_luminance(&exposed, |_lum| _lum / (1.0 + _lum)),
+            ToneMap::ExtendedReinhard => {
+                let white_sq = self.white * self.white;
+                Self::scale_luminance(&exposed, |_lum| _lum * (1.0 + _lum / white_sq) / (1.0 + _lum))
+            }
+            ToneMap::Aces => Colour::new(Self::aces(exposed.x), Self::aces(exposed.y), Self::aces(exposed.z))
+        };
+
+        let mapped = Colour::clamp(&mapped);
+        Colour::new(linear_to_srgb(mapped.x), linear_to_srgb(mapped.y), linear_to_srgb(mapped.z))
+    }
+
+    /// # `scale_luminance`
+    /// Maps the luminance of the given colour with the given function and scales the colour to the new luminance, which keeps the hue
+    fn scale_luminance<F: Fn(f32) -> f32>(colour: &Colour, map: F) -> Colour {
+        let luminance = Colour::luminance(colour);
+        if luminance <= 0.0 {
+            return *colour;
+        }
+
+        *colour * (map(luminance) / luminance)
+    }
+
+    /// # `aces`
+    /// Fit of the ACES filmic curve by Krzysztof Narkowicz for a single channel
+    fn aces(x: f32) -> f32 {
+        (x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14)
+    }
+}
+
+/// # `linear_to_srgb`
+/// Encodes a linear channel value in the range (0.0, 1.0) with the sRGB transfer function
+pub fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        12.92 * value
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// # `srgb_to_linear`
+/// Decodes an sRGB encoded channel value in the range (0.0, 1.0) into a linear value
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
@@ -1,9 +1,12 @@
-use super::Colour;
+use super::{Colour, Film};
+use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::path::Path;
 extern crate image;
+use image::codecs::hdr::HdrEncoder;
 
 /// # `Image`
-/// Struct to save pixels stored as `Colour`s and the width and height of an image. Has an function to save the image to png
+/// Struct to save pixels stored as linear `Colour`s and the width and height of an image. Has an function to save the image to png, Radiance .hdr or .pfm
 pub struct Image {
     pub pixels: Vec<Colour>,
     width: usize,
@@ -16,37 +19,76 @@ impl Image {
     pub fn new(width: usize, height: usize) -> Image {
         Image {
             pixels: vec![Colour::new(0.0, 0.0, 0.0); width * height],
-            width: width,
-            height: height
+            width,
+            height
         }
     }
 
-    pub fn at(&mut self, i: usize, j: usize) -> &mut Colour {
-        &mut self.pixels[j * self.width + i]
-    }
-
     /// # `save`
-    /// Saves the image as a .png file (using the Image crate) with given name as `String`
-    pub fn save(&self, output_name: String) -> std::io::Result<()> {
-        // Handle lack of extension
-        let output_name = if output_name.ends_with(".png") {output_name} else {output_name + ".png"};
+    /// Saves the image with given name as `String`. The format is chosen by the extension, `.hdr` and `.pfm` keep the linear floating point colours
+    /// while anything else is saved as a .png file (using the Image crate) developed by the given `Film`
+    pub fn save(&self, output_name: String, film: &Film) -> std::io::Result<()> {
+        match Path::new(&output_name).extension().and_then(|_ext| _ext.to_str()) {
+            Some("hdr") => self.save_hdr(&output_name),
+            Some("pfm") => self.save_pfm(&output_name),
+            Some("png") => self.save_png(&output_name, film),
+            _ => self.save_png(&(output_name + ".png"), film) // Handle lack of extension
+        }
+    }
 
+    /// # `save_png`
+    /// Saves the image as an 8-bit .png file. Colours are tone mapped and sRGB encoded by the given `Film`
+    fn save_png(&self, output_name: &str, film: &Film) -> std::io::Result<()> {
         // Store colour as bytes of RGB, hence *3 at the end
         let mut byte_array : Vec<u8> = Vec::with_capacity(self.width * self.height * 3);
 
         // Store pixels from top to bottom while converting the colours from the range (0.0, 1.0) to bytes in (0,255)
         for j in (0..self.height).rev() {
             for i in 0..self.width {
-                let byte = self.pixels[j * self.width + i];
+                let byte = film.develop(&self.pixels[j * self.width + i]);
                 byte_array.push((255.99 * byte.x) as u8);
-                byte_array.push((255.99 * byte.y) as u8); 
+                byte_array.push((255.99 * byte.y) as u8);
                 byte_array.push((255.99 * byte.z) as u8);
             }
         }
 
         // Save using the Image crate
-        image::save_buffer(&Path::new(output_name.as_str()), &byte_array, self.width as u32, self.height as u32, image::ColorType::Rgb8).ok();
+        image::save_buffer(Path::new(output_name), &byte_array, self.width as u32, self.height as u32, image::ColorType::Rgb8)
+            .map_err(|_error| std::io::Error::other(_error.to_string()))
+    }
+
+    /// # `save_hdr`
+    /// Saves the linear colours as a Radiance .hdr file (using the Image crate)
+    fn save_hdr(&self, output_name: &str) -> std::io::Result<()> {
+        // Store pixels from top to bottom
+        let mut pixels: Vec<image::Rgb<f32>> = Vec::with_capacity(self.width * self.height);
+        for j in (0..self.height).rev() {
+            for i in 0..self.width {
+                let pixel = self.pixels[j * self.width + i];
+                pixels.push(image::Rgb([pixel.x.max(0.0), pixel.y.max(0.0), pixel.z.max(0.0)]));
+            }
+        }
 
-        Ok(())
+        HdrEncoder::new(BufWriter::new(File::create(output_name)?))
+            .encode(&pixels, self.width, self.height)
+            .map_err(|_error| std::io::Error::other(_error.to_string()))
     }
-}
\ No newline at end of file
+
+    /// # `save_pfm`
+    /// Saves the linear colours as a little endian 32-bit float .pfm file
+    fn save_pfm(&self, output_name: &str) -> std::io::Result<()> {
+        let mut file = BufWriter::new(File::create(output_name)?);
+
+        // Header, a negative scale means little endian
+        write!(file, "PF\n{} {}\n-1.0\n", self.width, self.height)?;
+
+        // PFM stores pixels from bottom to top, which is the same order as the image
+        for pixel in &self.pixels {
+            for channel in [pixel.x, pixel.y, pixel.z] {
+                file.write_all(&channel.to_le_bytes())?;
+            }
+        }
+
+        file.flush()
+    }
+}
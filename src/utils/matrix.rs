@@ -0,0 +1,197 @@
+use std::ops;
+use super::Vector3;
+
+#[derive(Copy, Clone, Debug)]
+/// # `Matrix4`
+/// 4x4 matrix stored row by row. Used for affine transforms of points and vectors in 3-dimensional space
+pub struct Matrix4 {
+    pub m: [[f32; 4]; 4]
+}
+
+impl Matrix4 {
+    /// # `identity`
+    /// Returns the identity matrix
+    pub fn identity() -> Matrix4 {
+        Matrix4 {
+            m: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0]
+            ]
+        }
+    }
+
+    /// # `translation`
+    /// Returns the matrix that moves points by the given `Vector3`
+    pub fn translation(offset: Vector3) -> Matrix4 {
+        let mut matrix = Matrix4::identity();
+        matrix.m[0][3] = offset.x;
+        matrix.m[1][3] = offset.y;
+        matrix.m[2][3] = offset.z;
+        matrix
+    }
+
+    /// # `scaling`
+    /// Returns the matrix that scales each axis by the corresponding component of the given `Vector3`
+    pub fn scaling(scale: Vector3) -> Matrix4 {
+        let mut matrix = Matrix4::identity();
+        matrix.m[0][0] = scale.x;
+        matrix.m[1][1] = scale.y;
+        matrix.m[2][2] = scale.z;
+        matrix
+    }
+
+    /// # `rotation`
+    /// Returns the matrix that rotates by the given angles in degrees around the x, y and z axis (in that order) as `Vector3`
+    pub fn rotation(degrees: Vector3) -> Matrix4 {
+        let (sin_x, cos_x) = degrees.x.to_radians().sin_cos();
+        let (sin_y, cos_y) = degrees.y.to_radians().sin_cos();
+        let (sin_z, cos_z) = degrees.z.to_radians().sin_cos();
+
+        let mut rot_x = Matrix4::identity();
+        rot_x.m[1] = [0.0, cos_x, -sin_x, 0.0];
+        rot_x.m[2] = [0.0, sin_x, cos_x, 0.0];
+
+        let mut rot_y = Matrix4::identity();
+        rot_y.m[0] = [cos_y, 0.0, sin_y, 0.0];
+        rot_y.m[2] = [-sin_y, 0.0, cos_y, 0.0];
+
+        let mut rot_z = Matrix4::identity();
+        rot_z.m[0] = [cos_z, -sin_z, 0.0, 0.0];
+        rot_z.m[1] = [sin_z, cos_z, 0.0, 0.0];
+
+        rot_z * rot_y * rot_x
+    }
+
+    /// # `transpose`
+    /// Returns the transpose of this matrix
+    pub fn transpose(&self) -> Matrix4 {
+        let mut matrix = Matrix4::identity();
+        for (row, _values) in matrix.m.iter_mut().enumerate() {
+            for (col, _value) in _values.iter_mut().enumerate() {
+                *_value = self.m[col][row];
+            }
+        }
+        matrix
+    }
+
+    /// # `inverse`
+    /// Returns the inverse of this matrix (Gauss-Jordan elimination) or `None` if the matrix is singular
+    pub fn inverse(&self) -> Option<Matrix4> {
+        let mut a = self.m.map(|_row| _row.map(|_val| _val as f64));
+        let mut inv = Matrix4::identity().m.map(|_row| _row.map(|_val| _val as f64));
+
+        for col in 0..4 {
+            // Use the row with the largest value in the column as pivot
+            let pivot = (col..4).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs())).unwrap();
+            if a[pivot][col].abs() < 1.0e-12 {
+                return None;
+            }
+            a.swap(col, pivot);
+            inv.swap(col, pivot);
+
+            let scale = 1.0 / a[col][col];
+            for k in 0..4 {
+                a[col][k] *= scale;
+                inv[col][k] *= scale;
+            }
+
+            for row in 0..4 {
+                if row == col {
+                    continue;
+                }
+                let factor = a[row][col];
+                for k in 0..4 {
+                    a[row][k] -= factor * a[col][k];
+                    inv[row][k] -= factor * inv[col][k];
+                }
+            }
+        }
+
+        Some(Matrix4 {
+            m: inv.map(|_row| _row.map(|_val| _val as f32))
+        })
+    }
+
+    /// # `transform_point`
+    /// Returns the given point `Vector3` transformed by this matrix (translation included)
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        let m = &self.m;
+        Vector3::new(
+            m[0][0] * point.x + m[0][1] * point.y + m[0][2] * point.z + m[0][3],
+            m[1][0] * point.x + m[1][1] * point.y + m[1][2] * point.z + m[1][3],
+            m[2][0] * point.x + m[2][1] * point.y + m[2][2] * point.z + m[2][3]
+        )
+    }
+
+    /// # `transform_vector`
+    /// Returns the given direction `Vector3` transformed by this matrix (translation ignored)
+    pub fn transform_vector(&self, vector: Vector3) -> Vector3 {
+        let m = &self.m;
+        Vector3::new(
+            m[0][0] * vector.x + m[0][1] * vector.y + m[0][2] * vector.z,
+            m[1][0] * vector.x + m[1][1] * vector.y + m[1][2] * vector.z,
+            m[2][0] * vector.x + m[2][1] * vector.y + m[2][2] * vector.z
+        )
+    }
+}
+
+// Matrix * Matrix
+impl ops::Mul<Matrix4> for Matrix4 {
+    type Output = Matrix4;
+
+    fn mul(self, _rhs: Matrix4) -> Matrix4 {
+        let mut matrix = Matrix4 {m: [[0.0; 4]; 4]};
+        for row in 0..4 {
+            for col in 0..4 {
+                matrix.m[row][col] = (0..4).map(|k| self.m[row][k] * _rhs.m[k][col]).sum();
+            }
+        }
+        matrix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_identity(matrix: &Matrix4) {
+        for (row, _values) in matrix.m.iter().enumerate() {
+            for (col, _value) in _values.iter().enumerate() {
+                let expected = if row == col {1.0} else {0.0};
+                assert!((_value - expected).abs() < 1.0e-5, "m[{}][{}] is {}, expected {}", row, col, _value, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_undoes_the_matrix() {
+        let matrix = Matrix4::translation(Vector3::new(1.0, -2.0, 3.0))
+            * Matrix4::rotation(Vector3::new(30.0, 45.0, -60.0))
+            * Matrix4::scaling(Vector3::new(2.0, 0.5, 3.0));
+        let inverse = matrix.inverse().unwrap();
+
+        assert_identity(&(inverse * matrix));
+        assert_identity(&(matrix * inverse));
+    }
+
+    #[test]
+    fn inverse_needs_a_row_swap() {
+        // The first pivot is 0, so the elimination has to swap rows
+        let mut matrix = Matrix4::identity();
+        matrix.m[0] = [0.0, 1.0, 0.0, 0.0];
+        matrix.m[1] = [1.0, 0.0, 0.0, 0.0];
+
+        assert_identity(&(matrix.inverse().unwrap() * matrix));
+    }
+
+    #[test]
+    fn singular_matrix_has_no_inverse() {
+        assert!(Matrix4::scaling(Vector3::new(1.0, 0.0, 1.0)).inverse().is_none());
+
+        let mut matrix = Matrix4::identity();
+        matrix.m[2] = matrix.m[1];
+        assert!(matrix.inverse().is_none());
+    }
+}
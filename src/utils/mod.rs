@@ -1,15 +1,25 @@
-use rand::{Rng};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use std::cell::RefCell;
 
 mod vector;
 mod image;
+mod matrix;
+mod perlin;
+mod film;
+mod checkpoint;
+mod polynomial;
 
+// Default resolution, can be changed by the RES command or the -w and -h arguments
 pub const ASPECT_RATIO : f32 = 16.0 / 9.0;
 pub const IMG_WIDTH : usize = 800;
 pub const IMG_HEIGHT : usize = (IMG_WIDTH as f32 / ASPECT_RATIO) as usize;
 pub const BOUNCE_AMOUNT : usize = 10;
 pub const SAMPLES : usize = 100;
+pub const TILE_SIZE : usize = 32;
+pub const PASS_SAMPLES : usize = 8; // Samples per progressive pass when only the snapshot interval is given
+pub const AO_DISTANCE : f32 = 1.0;
 
-pub const INFINITY: f32 = std::f32::INFINITY;
+pub const INFINITY: f32 = f32::INFINITY;
 pub const ORIGIN: Vector3 = Vector3 {x: 0.0, y: 0.0, z: 0.0};
 pub const X_UNIT: Vector3 = Vector3 {x: 1.0, y: 0.0, z: 0.0};
 pub const Y_UNIT: Vector3 = Vector3 {x: 0.0, y: 1.0, z: 0.0};
@@ -21,9 +31,20 @@ pub const EPSILON: f32 = 1.0e-8;
 
 pub use self::{
     vector::Vector3,
-    image::Image
+    image::Image,
+    matrix::Matrix4,
+    perlin::Perlin,
+    film::{Film, ToneMap, srgb_to_linear},
+    checkpoint::{SampleBuffer, fnv1a},
+    polynomial::solve_quartic
 };
 
+/// # `default_threads`
+/// Returns the amount of worker threads used for rendering when none is given, which is the amount of available cores
+pub fn default_threads() -> usize {
+    std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
 /// # `Colour`
 /// Alias for `Vector3`
 /// 
@@ -33,7 +54,7 @@ pub type Colour = Vector3;
 impl Colour {
     /// # `to_u32`
     /// Returns the `u32` representation (0RGB in range[0,255]) 
-    pub fn to_u32(&self) -> u32 {
+    pub fn to_u32(self) -> u32 {
        ((255.99 * self.x) as u32) << 16|
        ((255.99 * self.y) as u32) << 8 |
        ((255.99 * self.z) as u32)
@@ -43,17 +64,54 @@ impl Colour {
     /// Clamps the values of the colours to be in between the range [0, 1.0]
     pub fn clamp(colour: &Colour) -> Colour {
         Colour {
-            x: colour.x.max(0.0).min(1.0),
-            y: colour.y.max(0.0).min(1.0),
-            z: colour.z.max(0.0).min(1.0)
+            x: colour.x.clamp(0.0, 1.0),
+            y: colour.y.clamp(0.0, 1.0),
+            z: colour.z.clamp(0.0, 1.0)
         }
     }
+
+    /// # `luminance`
+    /// Returns the brightness of the colour as seen by the eye (Rec. 709 weights)
+    pub fn luminance(colour: &Colour) -> f32 {
+        0.2126 * colour.x + 0.7152 * colour.y + 0.0722 * colour.z
+    }
+}
+
+thread_local! {
+    // Random generator of every thread, reseeded with `seed_random` so that the random values can be reproduced
+    static RNG: RefCell<SmallRng> = RefCell::new(SmallRng::from_entropy());
+}
+
+/// # `seed_random`
+/// Reseeds the random generator of the current thread with the given stream as `u64` of the given seed as `u64`.
+/// The same seed and stream always give the same random values, no matter which thread uses them
+pub fn seed_random(seed: u64, stream: u64) {
+    RNG.with(|_rng| *_rng.borrow_mut() = SmallRng::seed_from_u64(splitmix64(splitmix64(seed) ^ stream)));
+}
+
+/// # `random_seed`
+/// Returns a random seed for renders that are not given one
+pub fn random_seed() -> u64 {
+    rand::thread_rng().gen()
 }
 
 /// # `random_f32`
 /// returns a random value between in the range [0, 1.0)
 pub fn random_f32() -> f32 {
-    let mut rng = rand::thread_rng();
+    RNG.with(|_rng| _rng.borrow_mut().gen_range(0.0..1.0))
+}
+
+/// # `random_range`
+/// returns a random value in the given range as `f32` [min, max)
+pub fn random_range(min: f32, max: f32) -> f32 {
+    min + (max - min) * random_f32()
+}
 
-    rng.gen_range(0.0..1.0)
+/// # `splitmix64`
+/// Scrambles the bits of the given value, so that close seeds and streams give unrelated generators
+fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
 }
\ No newline at end of file
@@ -0,0 +1,98 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use super::Vector3;
+
+// Amount of lattice gradients, the noise repeats every POINT_COUNT units
+const POINT_COUNT: usize = 256;
+
+/// # `Perlin`
+/// Perlin gradient noise generator. Random unit gradients are placed on a lattice and blended smoothly in between.
+/// The gradients are generated from a seed so the same seed always gives the same noise
+pub struct Perlin {
+    gradients: Vec<Vector3>,
+    perm_x: Vec<usize>,
+    perm_y: Vec<usize>,
+    perm_z: Vec<usize>
+}
+
+impl Perlin {
+    /// # `new`
+    /// Creates a new noise generator using the given seed as `u64`
+    pub fn new(seed: u64) -> Perlin {
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let gradients = (0..POINT_COUNT)
+            .map(|_| loop {
+                let v = Vector3::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0));
+                let norm_squared = v.norm_squared();
+                if norm_squared > 1.0e-4 && norm_squared <= 1.0 {
+                    break v.unit();
+                }
+            })
+            .collect();
+
+        let permutation = |_rng: &mut StdRng| {
+            let mut perm: Vec<usize> = (0..POINT_COUNT).collect();
+            perm.shuffle(_rng);
+            perm
+        };
+
+        Perlin {
+            gradients,
+            perm_x: permutation(&mut rng),
+            perm_y: permutation(&mut rng),
+            perm_z: permutation(&mut rng)
+        }
+    }
+
+    /// # `noise`
+    /// Returns the noise at the given point `Vector3` as `f32` in the range [-1, 1]
+    pub fn noise(&self, point: &Vector3) -> f32 {
+        let (fx, fy, fz) = (point.x.floor(), point.y.floor(), point.z.floor());
+        let (u, v, w) = (point.x - fx, point.y - fy, point.z - fz);
+        let (i, j, k) = (fx as i64, fy as i64, fz as i64);
+
+        // Hermite smoothing of the blend weights
+        let (uu, vv, ww) = (u * u * (3.0 - 2.0 * u), v * v * (3.0 - 2.0 * v), w * w * (3.0 - 2.0 * w));
+
+        let mut sum = 0.0;
+        for di in 0..2 {
+            for dj in 0..2 {
+                for dk in 0..2 {
+                    let gradient = self.gradients[
+                        self.perm_x[Self::wrap(i + di)] ^ self.perm_y[Self::wrap(j + dj)] ^ self.perm_z[Self::wrap(k + dk)]
+                    ];
+                    let (ci, cj, ck) = (di as f32, dj as f32, dk as f32);
+                    let offset = Vector3::new(u - ci, v - cj, w - ck);
+
+                    sum += (ci * uu + (1.0 - ci) * (1.0 - uu))
+                         * (cj * vv + (1.0 - cj) * (1.0 - vv))
+                         * (ck * ww + (1.0 - ck) * (1.0 - ww))
+                         * gradient.dot(offset);
+                }
+            }
+        }
+
+        sum
+    }
+
+    /// # `turbulence`
+    /// Returns the sum of the absolute noise at the given point `Vector3` over the given amount of octaves as `usize`.
+    /// Every octave has double the frequency and half the weight of the previous one
+    pub fn turbulence(&self, point: &Vector3, octaves: usize) -> f32 {
+        let mut sum = 0.0;
+        let mut p = *point;
+        let mut weight = 1.0;
+
+        for _ in 0..octaves {
+            sum += weight * self.noise(&p).abs();
+            weight *= 0.5;
+            p *= 2.0;
+        }
+
+        sum
+    }
+
+    fn wrap(idx: i64) -> usize {
+        idx.rem_euclid(POINT_COUNT as i64) as usize
+    }
+}
@@ -0,0 +1,182 @@
+// Newton steps used to polish the roots found in closed form
+const POLISH_STEPS: usize = 2;
+
+/// # `solve_quartic`
+/// Returns the real roots of t⁴ + a3 t³ + a2 t² + a1 t + a0 = 0 in ascending order using Ferrari's method.
+/// Every root is polished with Newton steps on the original equation, since the closed form loses precision when roots are close together
+pub fn solve_quartic(a3: f64, a2: f64, a1: f64, a0: f64) -> Vec<f64> {
+    // Substituting t = y - a3 / 4 gives the depressed quartic y⁴ + p y² + q y + r = 0
+    let shift = a3 / 4.0;
+    let a3_sq = a3 * a3;
+    let p = a2 - 3.0 * a3_sq / 8.0;
+    let q = a1 - a3 * a2 / 2.0 + a3_sq * a3 / 8.0;
+    let r = a0 - a3 * a1 / 4.0 + a3_sq * a2 / 16.0 - 3.0 * a3_sq * a3_sq / 256.0;
+
+    let mut roots = Vec::with_capacity(4);
+    if q.abs() < 1.0e-12 {
+        // Biquadratic, z² + p z + r = 0 with z = y²
+        for z in solve_quadratic(p, r) {
+            if z >= 0.0 {
+                roots.push(z.sqrt());
+                roots.push(-z.sqrt());
+            }
+        }
+    } else {
+        // A positive root m of the resolvent cubic splits the quartic into y² ± s y + (p / 2 + m ∓ q / (2 s)) with s = sqrt(2 m)
+        let m = solve_cubic(p, p * p / 4.0 - r, -q * q / 8.0);
+        if m <= 0.0 {
+            return roots;
+        }
+
+        let s = (2.0 * m).sqrt();
+        for (sign, constant) in [(1.0, p / 2.0 + m - q / (2.0 * s)), (-1.0, p / 2.0 + m + q / (2.0 * s))] {
+            roots.extend(solve_quadratic(sign * s, constant));
+        }
+    }
+
+    let quartic = |_t: f64| (((_t + a3) * _t + a2) * _t + a1) * _t + a0;
+    let derivative = |_t: f64| ((4.0 * _t + 3.0 * a3) * _t + 2.0 * a2) * _t + a1;
+    let mut roots: Vec<f64> = roots
+        .into_iter()
+        .map(|_y| {
+            let mut t = _y - shift;
+            for _ in 0..POLISH_STEPS {
+                let slope = derivative(t);
+                if slope == 0.0 {
+                    break;
+                }
+                t -= quartic(t) / slope;
+            }
+            t
+        })
+        .collect();
+
+    roots.sort_by(|_a, _b| _a.total_cmp(_b));
+    roots
+}
+
+/// # `solve_cubic`
+/// Returns the largest real root of t³ + b t² + c t + d = 0 (there is always at least one)
+fn solve_cubic(b: f64, c: f64, d: f64) -> f64 {
+    // Substituting t = s - b / 3 gives the depressed cubic s³ + p s + q = 0
+    let shift = b / 3.0;
+    let p = c - b * b / 3.0;
+    let q = 2.0 * b * b * b / 27.0 - b * c / 3.0 + d;
+
+    let discriminant = q * q / 4.0 + p * p * p / 27.0;
+    let s = if discriminant > 0.0 {
+        // One real root (Cardano)
+        let sqrt_disc = discriminant.sqrt();
+        (-q / 2.0 + sqrt_disc).cbrt() + (-q / 2.0 - sqrt_disc).cbrt()
+    } else if p == 0.0 {
+        0.0
+    } else {
+        // Three real roots, the largest one of the trigonometric form
+        let radius = 2.0 * (-p / 3.0).sqrt();
+        let angle = (3.0 * q / (p * radius)).clamp(-1.0, 1.0).acos() / 3.0;
+        radius * angle.cos()
+    };
+
+    let t = s - shift;
+    let cubic = |_t: f64| ((_t + b) * _t + c) * _t + d;
+    let slope = (3.0 * t + 2.0 * b) * t + c;
+    if slope != 0.0 {t - cubic(t) / slope} else {t}
+}
+
+/// # `solve_quadratic`
+/// Returns the real roots of t² + b t + c = 0. Uses the form that does not cancel digits when b² is much larger than c
+fn solve_quadratic(b: f64, c: f64) -> Vec<f64> {
+    let discriminant = b * b - 4.0 * c;
+    if discriminant < 0.0 {
+        return Vec::new();
+    }
+
+    let root = -0.5 * (b + b.signum() * discriminant.sqrt());
+    if root == 0.0 {
+        return vec![0.0, 0.0];
+    }
+
+    vec![root, c / root]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_roots(found: &[f64], expected: &[f64]) {
+        assert_eq!(found.len(), expected.len(), "found the roots {:?}, expected {:?}", found, expected);
+        for (_found, _expected) in found.iter().zip(expected) {
+            assert!((_found - _expected).abs() < 1.0e-6, "found the roots {:?}, expected {:?}", found, expected);
+        }
+    }
+
+    #[test]
+    fn quartic_with_four_roots() {
+        // (t + 2)(t - 1)(t - 3)(t - 4) = t⁴ - 6 t³ + 3 t² + 26 t - 24
+        assert_roots(&solve_quartic(-6.0, 3.0, 26.0, -24.0), &[-2.0, 1.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn quartic_with_double_roots() {
+        // (t - 1)² (t - 3)² = t⁴ - 8 t³ + 22 t² - 24 t + 9
+        assert_roots(&solve_quartic(-8.0, 22.0, -24.0, 9.0), &[1.0, 1.0, 3.0, 3.0]);
+        // (t - 2)² (t² + 1) = t⁴ - 4 t³ + 5 t² - 4 t + 4
+        assert_roots(&solve_quartic(-4.0, 5.0, -4.0, 4.0), &[2.0, 2.0]);
+    }
+
+    #[test]
+    fn biquadratic_quartic() {
+        // (t² - 1)(t² - 4) = t⁴ - 5 t² + 4
+        assert_roots(&solve_quartic(0.0, -5.0, 0.0, 4.0), &[-2.0, -1.0, 1.0, 2.0]);
+        // (t² + 1)(t² - 9) = t⁴ - 8 t² - 9
+        assert_roots(&solve_quartic(0.0, -8.0, 0.0, -9.0), &[-3.0, 3.0]);
+    }
+
+    #[test]
+    fn quartic_without_real_roots() {
+        // (t² + 1)(t² + 2t + 5) = t⁴ + 2 t³ + 6 t² + 2 t + 5
+        assert!(solve_quartic(2.0, 6.0, 2.0, 5.0).is_empty());
+        assert!(solve_quartic(0.0, 0.0, 0.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn quartic_of_a_ray_through_a_torus() {
+        // Torus around the z axis with R = 1 and r = 0.25, ray from (-5, 0, 0) along x.
+        // (|o + t d|² + R² - r²)² = 4 R² ((o + t d)x² + (o + t d)y²) expanded in t
+        let (major, minor) = (1.0_f64, 0.25_f64);
+        let (o, d) = ([-5.0_f64, 0.0, 0.0], [1.0_f64, 0.0, 0.0]);
+        let b = o[0] * d[0] + o[1] * d[1] + o[2] * d[2];
+        let k = o[0] * o[0] + o[1] * o[1] + o[2] * o[2] + major * major - minor * minor;
+        let planar_dd = d[0] * d[0] + d[1] * d[1];
+        let planar_od = o[0] * d[0] + o[1] * d[1];
+        let planar_oo = o[0] * o[0] + o[1] * o[1];
+        let four_r2 = 4.0 * major * major;
+
+        let roots = solve_quartic(
+            4.0 * b,
+            4.0 * b * b + 2.0 * k - four_r2 * planar_dd,
+            4.0 * b * k - 2.0 * four_r2 * planar_od,
+            k * k - four_r2 * planar_oo
+        );
+
+        // The ray goes in and out of the tube on both sides of the hole, the first hit is at 5 - 1.25
+        assert_roots(&roots, &[3.75, 4.25, 5.75, 6.25]);
+    }
+
+    #[test]
+    fn cubic_gives_the_largest_root() {
+        // (t + 1)(t - 2)(t - 5) = t³ - 6 t² + 3 t + 10
+        assert!((solve_cubic(-6.0, 3.0, 10.0) - 5.0).abs() < 1.0e-9);
+        // (t - 1)(t² + 1) = t³ - t² + t - 1
+        assert!((solve_cubic(-1.0, 1.0, -1.0) - 1.0).abs() < 1.0e-9);
+    }
+
+    #[test]
+    fn quadratic_without_cancellation() {
+        // (t - 1.0e-8)(t - 1.0e8), the small root would be lost to cancellation in the textbook formula
+        let roots = solve_quadratic(-(1.0e8 + 1.0e-8), 1.0);
+        assert!(roots.iter().any(|_root| (_root - 1.0e8).abs() < 1.0e-6));
+        assert!(roots.iter().any(|_root| (_root - 1.0e-8).abs() < 1.0e-20));
+        assert!(solve_quadratic(0.0, 1.0).is_empty());
+    }
+}
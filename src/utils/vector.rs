@@ -1,8 +1,7 @@
 use std::ops;
-use rand::Rng;
-use super::{random_f32, EPSILON};
+use super::{random_f32, random_range, EPSILON};
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Default)]
 /// # `Vector3`
 /// Vector in 3-dimensional space. Includes algebraic functions such as dot, cross, norm
 pub struct Vector3 {
@@ -16,9 +15,9 @@ impl Vector3 {
     /// Returns a `Vector3` by taking its given corresponding x, y, z components as `f32`
     pub fn new(x: f32, y:f32, z:f32) -> Vector3 {
         Vector3 {
-            x: x,
-            y: y,
-            z: z
+            x,
+            y,
+            z
         }
     }
 
@@ -80,11 +79,10 @@ impl Vector3 {
     /// # `random_bounded`
     /// Creates a `Vector3` with components of random size in given range as `f32` range [min, max]
     pub fn random_bounded(min: f32, max: f32) -> Vector3 {
-        let mut rng = rand::thread_rng();
         Vector3 {
-            x: rng.gen_range(min..max),
-            y: rng.gen_range(min..max),
-            z: rng.gen_range(min..max)
+            x: random_range(min, max),
+            y: random_range(min, max),
+            z: random_range(min, max)
         }
     }
 
@@ -98,6 +96,16 @@ impl Vector3 {
         }
     }
 
+    /// # `random_in_unit_disk`
+    /// Creates a random `Vector3` that is within a unit disk in the xy plane (z is 0)
+    pub fn random_in_unit_disk() -> Vector3 {
+        loop {
+            let vect = Vector3::new(random_range(-1.0, 1.0), random_range(-1.0, 1.0), 0.0);
+            if vect.norm_squared() >= 1.0 {continue;}
+            return vect;
+        }
+    }
+
     /// # `random_in_hemisphere`
     /// Generates a random `Vector3` that is with an a hemisphere of a unit radius
     pub fn random_in_hemisphere(normal: &Vector3) -> Vector3 {
@@ -109,12 +117,32 @@ impl Vector3 {
         -in_unit_sphere
     }
 
+    /// # `basis`
+    /// Returns two unit vectors `(Vector3, Vector3)` that are perpendicular to this unit vector and to each other
+    pub fn basis(&self) -> (Vector3, Vector3) {
+        // Branchless basis by Duff et al.
+        let sign = 1f32.copysign(self.z);
+        let a = -1.0 / (sign + self.z);
+        let b = self.x * self.y * a;
+
+        (
+            Vector3::new(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            Vector3::new(b, sign + self.y * self.y * a, -self.y)
+        )
+    }
+
     /// # `near_zero`
     /// Returns true if vector is very close to zero
     pub fn near_zero(&self) -> bool {
         self.x.abs() < EPSILON && self.y < EPSILON && self.z < EPSILON
     }
 
+    /// # `is_finite`
+    /// Returns true if no component of the vector is infinite or NaN
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
     /// # `refract`
     /// Returns the refraction of this vector as `Vector3` using Snell's Law of Refraction and
     /// using the given normal vector `Vector3` and the index of refraction as `f32`.
@@ -220,6 +248,20 @@ impl ops::Div<f32> for Vector3 {
     }
 }
 
+// Vector[axis], 0 = x, 1 = y, 2 = z
+impl ops::Index<usize> for Vector3 {
+    type Output = f32;
+
+    fn index(&self, axis: usize) -> &f32 {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Vector3 index out of bounds: {}", axis)
+        }
+    }
+}
+
 // -Vector
 impl ops::Neg<> for Vector3 {
     type Output = Self;